@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+/// Attached to a baseball while its aerodynamics are active.
+#[derive(Debug, Component, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub(crate) struct AeroState {
+    /// seam orientation relative to the ball's rigid body at release
+    pub seam_rotation: Quat,
+    /// seconds since release
+    pub elapsed: f32,
+    /// forces applied during the last physics step
+    #[reflect(ignore)]
+    pub forces: AeroForces,
+    /// set for knuckleballs
+    #[reflect(ignore)]
+    pub knuckle: Option<Knuckle>,
+}
+
+impl AeroState {
+    pub(crate) fn new(seam_y_angle: f32, seam_z_angle: f32, knuckle: Option<Knuckle>) -> Self {
+        Self {
+            seam_rotation: seam_rotation(seam_y_angle, seam_z_angle),
+            elapsed: 0.,
+            forces: AeroForces::default(),
+            knuckle,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+mod components;
+mod model;
+mod resources;
+mod simulation;
+mod systems;
+
+use crate::prelude::*;
+use systems::*;
+
+pub(crate) mod prelude {
+    /// regulation baseball mass in kg
+    pub(crate) const BASEBALL_MASS: f32 = 0.145;
+    /// regulation baseball radius in meters (9.125 in circumference).
+    /// Sizes the ball on screen; flight uses the [`AeroProfile`] instead.
+    pub(crate) const BASEBALL_RADIUS: f32 = 0.0366;
+    /// scale of the `Baseball.glb` blueprint for a ball of `BASEBALL_RADIUS`
+    pub(crate) const BASEBALL_BLUEPRINT_SCALE: f32 = 0.037;
+
+    pub(crate) use super::*;
+    pub(crate) use components::*;
+    pub(crate) use model::*;
+    pub(crate) use resources::*;
+    pub(crate) use simulation::*;
+}
+
+/// Aerodynamic forces on the baseball.
+///
+/// `BaseballFlightPlugin` still activates the ball and tracks its flight state,
+/// but drag, Magnus and seam-shifted wake are applied here from the same model the
+/// predictions fly, so they follow the current [`AeroEnvironment`] and [`AeroProfile`],
+/// and can be switched at runtime through [`AeroEffects`].
+#[derive(Debug)]
+pub(crate) struct AerodynamicsPlugin {
+    pub effects: AeroEffects,
+}
+
+impl Plugin for AerodynamicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AeroState>();
+
+        app.init_resource::<AeroEnvironment>()
+            .init_resource::<AeroProfile>()
+            .insert_resource(self.effects)
+            .init_resource::<GhostTrajectories>()
+            .init_resource::<ForceVisualization>()
+            .init_resource::<ForceHistory>();
+
+        app.add_systems(
+            Update,
+            (
+                track_activated_baseballs,
+                simulate_ghost_trajectories,
+                deactivate_landed_baseballs,
+            )
+                .chain()
+                .in_set(AeroActivationSet::PostActivation),
+        )
+        .add_systems(
+            Update,
+            apply_aero_forces
+                .in_set(UpdateBaseballFlightStateSet::PostUpdate)
+                .in_set(PhysicsStepSet),
+        )
+        .add_systems(Update, draw_ghost_trajectories)
+        .add_systems(
+            Update,
+            (
+                draw_force_arrows.run_if(|vis: Res<ForceVisualization>| vis.arrows),
+                force_plot.run_if(|vis: Res<ForceVisualization>| vis.plot),
+            ),
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use crate::prelude::*;
+use std::f32::consts::PI;
+use std::sync::OnceLock;
+
+const DRAG_COEFFICIENT: f32 = 0.35;
+/// drag coefficient below and above the drag crisis
+const DRAG_CRISIS_COEFFICIENTS: (f32, f32) = (0.5, 0.3);
+/// Reynolds number at the middle of the drag crisis for an MLB ball; higher seams trip
+/// the boundary layer sooner
+const CRITICAL_REYNOLDS_NUMBER: f32 = 1.3e5;
+/// Reynolds numbers over which the drag crisis happens
+const DRAG_CRISIS_WIDTH: f32 = 2.5e4;
+/// lift coefficient of a spinning ball under `LiftModel::Constant`
+const LIFT_COEFFICIENT: f32 = 0.2;
+/// lift coefficient per unit of seam asymmetry (see `seam_asymmetry`)
+const SSW_COEFFICIENT: f32 = 0.4;
+/// polar angles from the stagnation point where seams shift the separation line
+const SEPARATION_BAND: (f32, f32) = (95. * PI / 180., 115. * PI / 180.);
+const SEAM_SAMPLES: usize = 96;
+/// controls how far the seam curve wanders from the equator
+const SEAM_SHAPE: f32 = 0.44;
+/// spin in rpm above which the wake is steady and a knuckleball stops wobbling
+const KNUCKLE_MAX_SPIN_RATE: f32 = 300.;
+const WOBBLE_MODES: usize = 3;
+/// range of each wobble mode's lateral force coefficient
+const WOBBLE_AMPLITUDE: (f32, f32) = (0.04, 0.12);
+/// range of each wobble mode's frequency in Hz
+const WOBBLE_FREQUENCY: (f32, f32) = (1.5, 6.);
+/// spin decay from Sawicki, Hubbard and Stronge (2003): dω/dt = -k ω v / r
+const SPIN_DECAY_COEFFICIENT: f32 = 2e-5;
+/// rad/s at which the transverse part of the spin turns around the flight direction,
+/// per unit of gyro fraction; a pure gyro or pure transverse spin axis doesn't move.
+/// Not a measured value: the aerodynamic torque on a pitched ball is small enough that
+/// tracking data shows the spin axis barely moving between release and the plate. 0.5 turns
+/// a half-gyro pitch's axis by about 6° over a 0.42 s flight (0.5 * 0.5 * 0.42 rad), which
+/// stays within that, and is why precession is off by default.
+const PRECESSION_RATE: f32 = 0.5;
+
+/// Orientation of the seams for the given seam angles.
+/// Matches the rotation applied to the `Baseball.glb` blueprint.
+pub(crate) fn seam_rotation(seam_y_angle: f32, seam_z_angle: f32) -> Quat {
+    Quat::from_rotation_y(-seam_y_angle).mul_quat(Quat::from_rotation_z(seam_z_angle))
+}
+
+/// Unit-sphere points along the seam of a ball in its local frame.
+fn seam_points() -> &'static [Vec3] {
+    static SEAM_POINTS: OnceLock<Vec<Vec3>> = OnceLock::new();
+    SEAM_POINTS.get_or_init(|| {
+        (0..SEAM_SAMPLES)
+            .map(|i| {
+                let t = 4. * PI * i as f32 / SEAM_SAMPLES as f32;
+                let polar = PI / 2. - (PI / 2. - SEAM_SHAPE) * t.cos();
+                let azimuth = t / 2. + SEAM_SHAPE * (2. * t).sin();
+                Vec3::new(
+                    polar.sin() * azimuth.cos(),
+                    polar.cos(),
+                    polar.sin() * azimuth.sin(),
+                )
+            })
+            .collect()
+    })
+}
+
+/// Net lateral direction of the seams sitting on the separation line,
+/// for a ball moving along `flow`. Zero when the seams are symmetric.
+pub(crate) fn seam_asymmetry(orientation: Quat, flow: Vec3) -> Vec3 {
+    let points = seam_points();
+    let asymmetry: Vec3 = points
+        .iter()
+        .map(|point| orientation * *point)
+        .filter(|point| {
+            let polar = point.dot(flow).clamp(-1., 1.).acos();
+            polar >= SEPARATION_BAND.0 && polar <= SEPARATION_BAND.1
+        })
+        .map(|point| point - flow * point.dot(flow))
+        .sum();
+    asymmetry / points.len() as f32
+}
+
+/// splitmix64, enough to turn a seed into reproducible wobble
+fn next_random(state: &mut u64) -> f32 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 24) as f32
+}
+
+#[derive(Debug, Clone, Copy)]
+struct WobbleMode {
+    /// direction of the force in the ball's frame, so it turns with the seams
+    axis: Vec3,
+    amplitude: f32,
+    frequency: f32,
+    phase: f32,
+}
+
+/// Unsteady wake of a ball thrown with almost no spin. The slowly turning seams shed
+/// vortices at changing spots, pushing the ball sideways in a direction that wanders
+/// over the flight. Modeled as a few oscillating lateral force modes fixed to the seams,
+/// drawn from a seed so a given knuckler reproduces.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Knuckle {
+    modes: [WobbleMode; WOBBLE_MODES],
+    /// seconds since release
+    pub elapsed: f32,
+}
+
+impl Knuckle {
+    pub(crate) fn from_seed(seed: u64) -> Self {
+        let mut state = seed;
+        let mut between = |(low, high): (f32, f32)| low + (high - low) * next_random(&mut state);
+        let modes = [(); WOBBLE_MODES].map(|_| {
+            let polar = between((-1., 1.)).acos();
+            let azimuth = between((0., 2. * PI));
+            WobbleMode {
+                axis: Vec3::new(
+                    polar.sin() * azimuth.cos(),
+                    polar.cos(),
+                    polar.sin() * azimuth.sin(),
+                ),
+                amplitude: between(WOBBLE_AMPLITUDE),
+                frequency: between(WOBBLE_FREQUENCY),
+                phase: between((0., 2. * PI)),
+            }
+        });
+        Self { modes, elapsed: 0. }
+    }
+
+    /// Lateral force coefficient for a ball moving along `flow`.
+    fn lateral_coefficient(&self, orientation: Quat, flow: Vec3, spin: Vec3) -> Vec3 {
+        let spin_rate = spin.length() * RADS_TO_RPM;
+        let strength = (1. - spin_rate / KNUCKLE_MAX_SPIN_RATE).clamp(0., 1.);
+        if strength <= 0. {
+            return Vec3::ZERO;
+        }
+        self.modes
+            .iter()
+            .map(|mode| {
+                let axis = orientation * mode.axis;
+                let lateral = (axis - flow * axis.dot(flow)).normalize_or_zero();
+                let oscillation = (2. * PI * mode.frequency * self.elapsed + mode.phase).sin();
+                lateral * mode.amplitude * oscillation
+            })
+            .sum::<Vec3>()
+            * strength
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BallState {
+    /// velocity in m/s
+    pub velocity: Vec3,
+    /// angular velocity in rad/s
+    pub spin: Vec3,
+    /// seam orientation in world coordinates
+    pub orientation: Quat,
+    /// set for knuckleballs
+    pub knuckle: Option<Knuckle>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct AeroForces {
+    pub drag: Vec3,
+    pub magnus: Vec3,
+    /// seam-shifted wake
+    pub ssw: Vec3,
+}
+
+impl AeroForces {
+    pub(crate) fn total(&self) -> Vec3 {
+        self.drag + self.magnus + self.ssw
+    }
+
+    /// Zeroes out the effects that are switched off.
+    pub(crate) fn masked(&self, effects: &AeroEffects) -> Self {
+        Self {
+            drag: if effects.drag_on {
+                self.drag
+            } else {
+                Vec3::ZERO
+            },
+            magnus: if effects.magnus_on {
+                self.magnus
+            } else {
+                Vec3::ZERO
+            },
+            ssw: if effects.ssw_on { self.ssw } else { Vec3::ZERO },
+        }
+    }
+}
+
+fn drag_coefficient(speed: f32, environment: &AeroEnvironment, profile: &AeroProfile) -> f32 {
+    match profile.drag_model {
+        DragModel::Constant => DRAG_COEFFICIENT,
+        DragModel::DragCrisis => {
+            let reynolds_number = environment.air_density() * speed * 2. * profile.radius()
+                / environment.dynamic_viscosity();
+            let critical = CRITICAL_REYNOLDS_NUMBER / profile.relative_seam_height();
+            let (subcritical, supercritical) = DRAG_CRISIS_COEFFICIENTS;
+            let crisis = 1. / (1. + (-(reynolds_number - critical) / DRAG_CRISIS_WIDTH).exp());
+            subcritical + (supercritical - subcritical) * crisis
+        }
+    }
+}
+
+fn lift_coefficient(spin_factor: f32, profile: &AeroProfile) -> f32 {
+    match profile.lift_model {
+        LiftModel::Constant => LIFT_COEFFICIENT,
+        LiftModel::SpinFactor => spin_factor / (0.4 + 2.32 * spin_factor),
+    }
+}
+
+/// Aerodynamic forces on the ball in newtons.
+pub(crate) fn aero_forces(
+    state: &BallState,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+) -> AeroForces {
+    let relative_velocity = state.velocity - environment.wind;
+    let speed = relative_velocity.length();
+    if speed < f32::EPSILON {
+        return AeroForces::default();
+    }
+    let flow = relative_velocity / speed;
+    let radius = profile.radius();
+    let dynamic_pressure = 0.5 * environment.air_density() * PI * radius.powi(2) * speed.powi(2);
+
+    let drag = -dynamic_pressure * drag_coefficient(speed, environment, profile) * flow;
+
+    // only the transverse part of the spin produces lift
+    let lift_axis = state.spin.cross(flow);
+    let magnus = if lift_axis.length() > f32::EPSILON {
+        let spin_factor = radius * lift_axis.length() / speed;
+        dynamic_pressure * lift_coefficient(spin_factor, profile) * lift_axis.normalize()
+    } else {
+        Vec3::ZERO
+    };
+
+    // seams on the separation line trip early separation on their side,
+    // pushing the ball the other way; taller seams trip it harder
+    let ssw = -dynamic_pressure
+        * SSW_COEFFICIENT
+        * profile.relative_seam_height()
+        * seam_asymmetry(state.orientation, flow)
+        + knuckle_force(state, environment, profile);
+
+    AeroForces { drag, magnus, ssw }
+}
+
+/// Unsteady lateral force on a knuckleball in newtons, zero for any other pitch.
+pub(crate) fn knuckle_force(
+    state: &BallState,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+) -> Vec3 {
+    let Some(knuckle) = &state.knuckle else {
+        return Vec3::ZERO;
+    };
+    let relative_velocity = state.velocity - environment.wind;
+    let speed = relative_velocity.length();
+    if speed < f32::EPSILON {
+        return Vec3::ZERO;
+    }
+    let dynamic_pressure =
+        0.5 * environment.air_density() * PI * profile.radius().powi(2) * speed.powi(2);
+    dynamic_pressure
+        * knuckle.lateral_coefficient(state.orientation, relative_velocity / speed, state.spin)
+}
+
+/// Forces averaged over a step of `dt` seconds.
+/// The seams turn many times a second, so a single sample per frame would alias the wake.
+pub(crate) fn mean_aero_forces(
+    state: &BallState,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+    dt: f32,
+    samples: usize,
+) -> AeroForces {
+    let samples = samples.max(1);
+    let mut mean = AeroForces::default();
+    for i in 0..samples {
+        let elapsed = dt * i as f32 / samples as f32;
+        let forces = aero_forces(
+            &BallState {
+                orientation: Quat::from_scaled_axis(state.spin * elapsed) * state.orientation,
+                knuckle: state.knuckle.map(|knuckle| Knuckle {
+                    elapsed: knuckle.elapsed + elapsed,
+                    ..knuckle
+                }),
+                ..*state
+            },
+            environment,
+            profile,
+        );
+        mean.drag += forces.drag / samples as f32;
+        mean.magnus += forces.magnus / samples as f32;
+        mean.ssw += forces.ssw / samples as f32;
+    }
+    mean
+}
+
+/// Spin after `dt` seconds of decay and gyro precession, for whichever of them is on.
+pub(crate) fn evolve_spin(
+    state: &BallState,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+    effects: &AeroEffects,
+    dt: f32,
+) -> Vec3 {
+    let relative_velocity = state.velocity - environment.wind;
+    let speed = relative_velocity.length();
+    let mut spin = state.spin;
+    if effects.spin_decay_on {
+        spin *= (1. - SPIN_DECAY_COEFFICIENT * speed / profile.radius() * dt).max(0.);
+    }
+    if effects.precession_on && speed > f32::EPSILON && spin.length() > f32::EPSILON {
+        // the transverse part turns around the flight direction, faster the more gyro there is
+        let flow = relative_velocity / speed;
+        let gyro = spin.normalize().dot(flow);
+        spin = Quat::from_axis_angle(flow, PRECESSION_RATE * gyro * dt) * spin;
+    }
+    spin
+}
@@ -0,0 +1,364 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Gas constant for dry air, J/(kg K)
+const R_DRY_AIR: f32 = 287.058;
+/// Gas constant for water vapor, J/(kg K)
+const R_WATER_VAPOR: f32 = 461.495;
+const STANDARD_SEA_LEVEL_PRESSURE: f32 = 1013.25;
+/// seam height of an MLB ball in meters (0.031 in)
+const MLB_SEAM_HEIGHT: f32 = 0.00079;
+const BASEBALL_RESTITUTION: f32 = 0.546;
+
+#[derive(Debug, Resource, Clone, Copy, PartialEq)]
+pub(crate) struct AeroEnvironment {
+    /// altitude above sea level in meters
+    pub altitude: f32,
+    /// air temperature in celsius
+    pub temperature: f32,
+    /// barometric pressure corrected to sea level in hPa, as given by weather reports
+    pub pressure: f32,
+    /// relative humidity [0, 1]
+    pub humidity: f32,
+    /// wind velocity in m/s, in world coordinates
+    pub wind: Vec3,
+}
+
+impl Default for AeroEnvironment {
+    fn default() -> Self {
+        EnvironmentPreset::SeaLevel.environment()
+    }
+}
+
+impl AeroEnvironment {
+    /// standard atmosphere at sea level in still air
+    pub(crate) fn standard() -> Self {
+        Self {
+            altitude: 0.,
+            temperature: 15.,
+            pressure: STANDARD_SEA_LEVEL_PRESSURE,
+            humidity: 0.,
+            wind: Vec3::ZERO,
+        }
+    }
+
+    /// pressure at the field's altitude in hPa
+    pub(crate) fn station_pressure(&self) -> f32 {
+        self.pressure * (1. - 2.25577e-5 * self.altitude).powf(5.25588)
+    }
+
+    /// saturation vapor pressure at the current temperature in hPa (Tetens)
+    fn saturation_vapor_pressure(&self) -> f32 {
+        6.1078 * 10_f32.powf(7.5 * self.temperature / (self.temperature + 237.3))
+    }
+
+    /// air density in kg/m^3, accounting for altitude, temperature and humidity
+    pub(crate) fn air_density(&self) -> f32 {
+        let temperature_k = self.temperature + 273.15;
+        let vapor_pressure = self.humidity.clamp(0., 1.) * self.saturation_vapor_pressure();
+        let dry_pressure = self.station_pressure() - vapor_pressure;
+        // hPa to Pa
+        (dry_pressure * 100.) / (R_DRY_AIR * temperature_k)
+            + (vapor_pressure * 100.) / (R_WATER_VAPOR * temperature_k)
+    }
+
+    /// dynamic viscosity of air in Pa s (Sutherland)
+    pub(crate) fn dynamic_viscosity(&self) -> f32 {
+        let temperature_k = self.temperature + 273.15;
+        1.458e-6 * temperature_k.powf(1.5) / (temperature_k + 110.4)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum DragModel {
+    /// the same drag coefficient at every speed
+    #[default]
+    Constant,
+    /// drag falls off through the drag crisis as the Reynolds number grows
+    DragCrisis,
+}
+
+impl DragModel {
+    pub(crate) const ALL: [DragModel; 2] = [DragModel::Constant, DragModel::DragCrisis];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            DragModel::Constant => "constant",
+            DragModel::DragCrisis => "drag crisis",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum LiftModel {
+    /// the same lift coefficient for any spinning ball
+    Constant,
+    /// lift grows with the spin factor, rω/v (Nathan)
+    #[default]
+    SpinFactor,
+}
+
+impl LiftModel {
+    pub(crate) const ALL: [LiftModel; 2] = [LiftModel::Constant, LiftModel::SpinFactor];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            LiftModel::Constant => "constant",
+            LiftModel::SpinFactor => "spin factor",
+        }
+    }
+}
+
+/// The ball and the coefficient models its forces are computed with.
+#[derive(Debug, Resource, Clone, Copy, PartialEq)]
+pub(crate) struct AeroProfile {
+    pub drag_model: DragModel,
+    pub lift_model: LiftModel,
+    /// kg
+    pub mass: f32,
+    /// meters
+    pub circumference: f32,
+    /// meters
+    pub seam_height: f32,
+    /// coefficient of restitution when the ball bounces
+    pub restitution: f32,
+}
+
+impl Default for AeroProfile {
+    fn default() -> Self {
+        BallPreset::Mlb.profile()
+    }
+}
+
+impl AeroProfile {
+    /// meters
+    pub(crate) fn radius(&self) -> f32 {
+        self.circumference / (2. * std::f32::consts::PI)
+    }
+
+    /// Seam height relative to an MLB ball's.
+    pub(crate) fn relative_seam_height(&self) -> f32 {
+        self.seam_height / MLB_SEAM_HEIGHT
+    }
+}
+
+/// Regulation balls of the leagues players move between. Values are the middle of each
+/// league's allowed range; seam heights are typical measured values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum BallPreset {
+    Mlb,
+    NpbKbo,
+    Ncaa,
+    FastpitchSoftball,
+    SlowpitchSoftball,
+}
+
+impl BallPreset {
+    pub(crate) const ALL: [BallPreset; 5] = [
+        BallPreset::Mlb,
+        BallPreset::NpbKbo,
+        BallPreset::Ncaa,
+        BallPreset::FastpitchSoftball,
+        BallPreset::SlowpitchSoftball,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            BallPreset::Mlb => "MLB",
+            BallPreset::NpbKbo => "NPB/KBO",
+            BallPreset::Ncaa => "NCAA",
+            BallPreset::FastpitchSoftball => "Fastpitch 12\"",
+            BallPreset::SlowpitchSoftball => "Slowpitch 12\"",
+        }
+    }
+
+    pub(crate) fn profile(&self) -> AeroProfile {
+        let (mass, circumference, seam_height, restitution) = match self {
+            // 5-5.25 oz, 9-9.25 in
+            BallPreset::Mlb => (
+                BASEBALL_MASS,
+                2. * std::f32::consts::PI * BASEBALL_RADIUS,
+                MLB_SEAM_HEIGHT,
+                BASEBALL_RESTITUTION,
+            ),
+            // 141.7-148.8 g, 22.9-23.5 cm, lower and tackier seams
+            BallPreset::NpbKbo => (0.1453, 0.2320, 0.00070, BASEBALL_RESTITUTION),
+            // same size as MLB, flat seams that still sit a little higher
+            BallPreset::Ncaa => (0.1460, 0.2318, 0.00090, BASEBALL_RESTITUTION),
+            // 6.25-7 oz, 12 in, raised stitches, .47 COR
+            BallPreset::FastpitchSoftball => (0.1870, 0.3048, 0.00060, 0.47),
+            // same size, flatter stitches, .44 COR
+            BallPreset::SlowpitchSoftball => (0.1870, 0.3048, 0.00040, 0.44),
+        };
+        AeroProfile {
+            drag_model: DragModel::default(),
+            lift_model: LiftModel::default(),
+            mass,
+            circumference,
+            seam_height,
+            restitution,
+        }
+    }
+}
+
+/// Which aerodynamic effects act on the ball, both in flight and in predictions.
+/// Switching one takes effect on the next physics step.
+#[derive(Debug, Resource, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct AeroEffects {
+    pub drag_on: bool,
+    pub magnus_on: bool,
+    /// seam-shifted wake
+    pub ssw_on: bool,
+    pub spin_decay_on: bool,
+    /// gyro precession of the spin axis
+    pub precession_on: bool,
+}
+
+impl Default for AeroEffects {
+    fn default() -> Self {
+        Self {
+            drag_on: true,
+            magnus_on: true,
+            ssw_on: true,
+            spin_decay_on: true,
+            precession_on: false,
+        }
+    }
+}
+
+/// Alternate paths of the last pitch, each simulated with one effect switched off.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct GhostTrajectories {
+    pub enabled: bool,
+    /// the baseball the ghosts were simulated for
+    pub baseball: Option<Entity>,
+    pub paths: Vec<GhostTrajectory>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct GhostTrajectory {
+    pub label: &'static str,
+    pub color: Color,
+    pub effects: AeroEffects,
+    pub trajectory: Trajectory,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ForceComponent {
+    #[default]
+    Magnitude,
+    X,
+    Y,
+    Z,
+}
+
+impl ForceComponent {
+    pub(crate) const ALL: [ForceComponent; 4] = [
+        ForceComponent::Magnitude,
+        ForceComponent::X,
+        ForceComponent::Y,
+        ForceComponent::Z,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            ForceComponent::Magnitude => "magnitude",
+            ForceComponent::X => "x",
+            ForceComponent::Y => "y",
+            ForceComponent::Z => "z",
+        }
+    }
+
+    pub(crate) fn of(&self, force: Vec3) -> f32 {
+        match self {
+            ForceComponent::Magnitude => force.length(),
+            ForceComponent::X => force.x,
+            ForceComponent::Y => force.y,
+            ForceComponent::Z => force.z,
+        }
+    }
+}
+
+/// Debug views of the forces acting on the ball in flight.
+#[derive(Debug, Resource)]
+pub(crate) struct ForceVisualization {
+    /// gizmo arrows on the ball
+    pub arrows: bool,
+    /// time-series plot of the last flight
+    pub plot: bool,
+    /// arrow length in meters per newton
+    pub scale: f32,
+    pub plot_component: ForceComponent,
+}
+
+impl Default for ForceVisualization {
+    fn default() -> Self {
+        Self {
+            arrows: false,
+            plot: false,
+            scale: 0.2,
+            plot_component: ForceComponent::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ForceSample {
+    /// seconds since release
+    pub time: f32,
+    pub gravity: Vec3,
+    pub forces: AeroForces,
+}
+
+/// Forces recorded every physics step of the last flight.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct ForceHistory {
+    pub samples: Vec<ForceSample>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum EnvironmentPreset {
+    SeaLevel,
+    Denver,
+    HumidNightGame,
+}
+
+impl EnvironmentPreset {
+    pub(crate) const ALL: [EnvironmentPreset; 3] = [
+        EnvironmentPreset::SeaLevel,
+        EnvironmentPreset::Denver,
+        EnvironmentPreset::HumidNightGame,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            EnvironmentPreset::SeaLevel => "Sea level",
+            EnvironmentPreset::Denver => "Denver",
+            EnvironmentPreset::HumidNightGame => "Humid night game",
+        }
+    }
+
+    pub(crate) fn environment(&self) -> AeroEnvironment {
+        match self {
+            // standard atmosphere
+            EnvironmentPreset::SeaLevel => AeroEnvironment::standard(),
+            // Coors Field on a summer afternoon
+            EnvironmentPreset::Denver => AeroEnvironment {
+                altitude: 1580.,
+                temperature: 27.,
+                pressure: STANDARD_SEA_LEVEL_PRESSURE,
+                humidity: 0.3,
+                wind: Vec3::ZERO,
+            },
+            // coastal park in late summer, after sundown
+            EnvironmentPreset::HumidNightGame => AeroEnvironment {
+                altitude: 10.,
+                temperature: 26.,
+                pressure: 1010.,
+                humidity: 0.9,
+                wind: Vec3::ZERO,
+            },
+        }
+    }
+}
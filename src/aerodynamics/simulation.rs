@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+pub(crate) const GRAVITY: Vec3 = Vec3::new(0., -9.81, 0.);
+/// integration step for simulated pitches in seconds
+const SIMULATION_DT: f32 = 0.001;
+/// simulated pitches are cut off after this many seconds
+const MAX_FLIGHT_TIME: f32 = 2.;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TrajectoryPoint {
+    pub time: f32,
+    pub position: Vec3,
+    pub state: BallState,
+    pub forces: AeroForces,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Trajectory {
+    pub points: Vec<TrajectoryPoint>,
+}
+
+impl Trajectory {
+    pub(crate) fn positions(&self) -> impl Iterator<Item = Vec3> + '_ {
+        self.points.iter().map(|point| point.position)
+    }
+
+    /// Where the ball crosses the plane at depth `z`, interpolated between steps.
+    pub(crate) fn position_at_z(&self, z: f32) -> Option<Vec3> {
+        self.points.windows(2).find_map(|pair| {
+            let (a, b) = (pair[0].position, pair[1].position);
+            if (a.z - z) * (b.z - z) <= 0. && a.z != b.z {
+                Some(a.lerp(b, (a.z - z) / (a.z - b.z)))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// State of the ball as it reaches the front of home plate.
+    pub(crate) fn plate_state(&self) -> Option<BallState> {
+        self.points
+            .iter()
+            .find(|point| point.position.z <= DEFAULT_FRONT_PANEL_POS_Z)
+            .map(|point| point.state)
+    }
+
+    /// Where the ball crosses the front of home plate.
+    pub(crate) fn plate_location(&self) -> Option<Vec3> {
+        self.position_at_z(DEFAULT_FRONT_PANEL_POS_Z)
+    }
+}
+
+/// Flies a ball from `position` with the given initial state, the same way
+/// the live baseball is flown, until it passes home plate or lands.
+pub(crate) fn simulate_flight(
+    position: Vec3,
+    state: BallState,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+    effects: &AeroEffects,
+) -> Trajectory {
+    let mut position = position;
+    let mut state = state;
+    let mut time = 0.;
+    let mut points = vec![];
+    while time <= MAX_FLIGHT_TIME {
+        let forces = aero_forces(&state, environment, profile).masked(effects);
+        points.push(TrajectoryPoint {
+            time,
+            position,
+            state,
+            forces,
+        });
+        if position.z < 0. || position.y < 0. {
+            break;
+        }
+        let spin = evolve_spin(&state, environment, profile, effects, SIMULATION_DT);
+        state.velocity += (GRAVITY + forces.total() / profile.mass) * SIMULATION_DT;
+        position += state.velocity * SIMULATION_DT;
+        state.orientation =
+            (Quat::from_scaled_axis(state.spin * SIMULATION_DT) * state.orientation).normalize();
+        state.spin = spin;
+        if let Some(knuckle) = state.knuckle.as_mut() {
+            knuckle.elapsed += SIMULATION_DT;
+        }
+        time += SIMULATION_DT;
+    }
+    Trajectory { points }
+}
+
+/// Simulates the pitch as it would be thrown along `direction` on `field`.
+pub(crate) fn simulate_pitch(
+    pitch: &PitchParams,
+    direction: Vec3,
+    field: &FieldGeometry,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+    effects: &AeroEffects,
+) -> Trajectory {
+    simulate_flight(
+        pitch.release_point(field),
+        pitch.release_state(direction),
+        environment,
+        profile,
+        effects,
+    )
+}
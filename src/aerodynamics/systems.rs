@@ -0,0 +1,256 @@
+use crate::prelude::*;
+
+/// the ball is considered dead once it is this far behind home plate
+const DEAD_BALL_Z: f32 = -2.;
+/// seam orientations sampled per physics step when averaging the forces
+const FORCE_SAMPLES_PER_STEP: usize = 16;
+const SPIN_AXIS_HALF_LENGTH: f32 = 0.15;
+
+const GRAVITY_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+const DRAG_COLOR: Color = Color::srgb(0.9, 0.2, 0.2);
+const MAGNUS_COLOR: Color = Color::srgb(0.9, 0.5, 0.1);
+const SSW_COLOR: Color = Color::srgb(0.2, 0.8, 0.9);
+const SPIN_AXIS_COLOR: Color = Color::srgb(0.9, 0.9, 0.2);
+
+/// Length of one physics step in seconds.
+pub(crate) fn physics_timestep(rapier_config: &RapierConfiguration, time: &Time) -> f32 {
+    match rapier_config.timestep_mode {
+        TimestepMode::Fixed { dt, .. } => dt,
+        TimestepMode::Interpolated { dt, .. } => dt,
+        TimestepMode::Variable {
+            max_dt, time_scale, ..
+        } => (time.delta_seconds() * time_scale).min(max_dt),
+    }
+}
+
+pub(crate) fn track_activated_baseballs(
+    mut commands: Commands,
+    mut force_history: ResMut<ForceHistory>,
+    selected_pitch_parameters: Res<SelectedPitchParameters>,
+    mut ev_activate_aerodynamics: EventReader<ActivateAerodynamicsEvent>,
+) {
+    for ev in ev_activate_aerodynamics.read() {
+        force_history.samples.clear();
+        if let Some(mut entity_commands) = commands.get_entity(ev.entity) {
+            entity_commands.insert(AeroState::new(
+                ev.seam_y_angle,
+                ev.seam_z_angle,
+                selected_pitch_parameters.0.knuckle(),
+            ));
+        }
+    }
+}
+
+pub(crate) fn deactivate_landed_baseballs(
+    mut commands: Commands,
+    query_baseball: Query<(Entity, &Transform, &Velocity), With<AeroState>>,
+) {
+    for (entity, transform, velocity) in query_baseball.iter() {
+        let landed = transform.translation.y <= BASEBALL_RADIUS;
+        let dead = transform.translation.z < DEAD_BALL_Z || velocity.linvel.z >= 0.;
+        if landed || dead {
+            commands.entity(entity).remove::<AeroState>();
+        }
+    }
+}
+
+/// Flies the ball with the same model as the predictions, so switching an effect or
+/// changing the air or the ball acts on the ball in flight right away.
+pub(crate) fn apply_aero_forces(
+    environment: Res<AeroEnvironment>,
+    profile: Res<AeroProfile>,
+    effects: Res<AeroEffects>,
+    rapier_config: Res<RapierConfiguration>,
+    time: Res<Time>,
+    mut force_history: ResMut<ForceHistory>,
+    mut query_baseball: Query<(&mut Velocity, &Transform, &mut AeroState)>,
+) {
+    let dt = physics_timestep(&rapier_config, &time);
+    for (mut velocity, transform, mut aero_state) in query_baseball.iter_mut() {
+        let state = BallState {
+            velocity: velocity.linvel,
+            spin: velocity.angvel,
+            orientation: transform.rotation * aero_state.seam_rotation,
+            knuckle: aero_state.knuckle.map(|knuckle| Knuckle {
+                elapsed: aero_state.elapsed,
+                ..knuckle
+            }),
+        };
+        let forces = mean_aero_forces(&state, &environment, &profile, dt, FORCE_SAMPLES_PER_STEP)
+            .masked(&effects);
+        velocity.linvel += forces.total() / profile.mass * dt;
+        velocity.angvel = evolve_spin(&state, &environment, &profile, &effects, dt);
+
+        force_history.samples.push(ForceSample {
+            time: aero_state.elapsed,
+            gravity: GRAVITY * profile.mass,
+            forces,
+        });
+        aero_state.forces = forces;
+        aero_state.elapsed += dt;
+    }
+}
+
+pub(crate) fn simulate_ghost_trajectories(
+    mut ghost_trajectories: ResMut<GhostTrajectories>,
+    environment: Res<AeroEnvironment>,
+    profile: Res<AeroProfile>,
+    effects: Res<AeroEffects>,
+    selected_pitch_parameters: Res<SelectedPitchParameters>,
+    mut ev_activate_aerodynamics: EventReader<ActivateAerodynamicsEvent>,
+    query_baseball: Query<(&Transform, &Velocity)>,
+) {
+    for ev in ev_activate_aerodynamics.read() {
+        if !ghost_trajectories.enabled {
+            continue;
+        }
+        let Ok((transform, velocity)) = query_baseball.get(ev.entity) else {
+            continue;
+        };
+        let state = BallState {
+            velocity: velocity.linvel,
+            spin: velocity.angvel,
+            orientation: transform.rotation * seam_rotation(ev.seam_y_angle, ev.seam_z_angle),
+            knuckle: selected_pitch_parameters.0.knuckle(),
+        };
+        let ghosts = [
+            ("as thrown", Color::srgb(0.9, 0.9, 0.9), *effects),
+            (
+                "no seam-shifted wake",
+                SSW_COLOR,
+                AeroEffects {
+                    ssw_on: false,
+                    ..*effects
+                },
+            ),
+            (
+                "no Magnus",
+                MAGNUS_COLOR,
+                AeroEffects {
+                    magnus_on: false,
+                    ..*effects
+                },
+            ),
+        ];
+        ghost_trajectories.baseball = Some(ev.entity);
+        ghost_trajectories.paths = ghosts
+            .into_iter()
+            .map(|(label, color, effects)| GhostTrajectory {
+                label,
+                color,
+                effects,
+                trajectory: simulate_flight(
+                    transform.translation,
+                    state,
+                    &environment,
+                    &profile,
+                    &effects,
+                ),
+            })
+            .collect();
+    }
+}
+
+pub(crate) fn draw_ghost_trajectories(
+    mut gizmos: Gizmos,
+    ghost_trajectories: Res<GhostTrajectories>,
+    query_baseball: Query<(), With<Velocity>>,
+) {
+    if !ghost_trajectories.enabled {
+        return;
+    }
+    // ghosts go away with the ball they were simulated for
+    let Some(baseball) = ghost_trajectories.baseball else {
+        return;
+    };
+    if !query_baseball.contains(baseball) {
+        return;
+    }
+    for ghost in ghost_trajectories.paths.iter() {
+        gizmos.linestrip(ghost.trajectory.positions(), ghost.color);
+        if let Some(plate_location) = ghost.trajectory.plate_location() {
+            gizmos.sphere(plate_location, Quat::IDENTITY, BASEBALL_RADIUS, ghost.color);
+        }
+    }
+}
+
+pub(crate) fn draw_force_arrows(
+    mut gizmos: Gizmos,
+    force_visualization: Res<ForceVisualization>,
+    profile: Res<AeroProfile>,
+    query_baseball: Query<(&Transform, &Velocity, &AeroState)>,
+) {
+    let scale = force_visualization.scale;
+    for (transform, velocity, aero_state) in query_baseball.iter() {
+        let origin = transform.translation;
+        let forces = aero_state.forces;
+        for (force, color) in [
+            (GRAVITY * profile.mass, GRAVITY_COLOR),
+            (forces.drag, DRAG_COLOR),
+            (forces.magnus, MAGNUS_COLOR),
+            (forces.ssw, SSW_COLOR),
+        ] {
+            if force.length() > f32::EPSILON {
+                gizmos.arrow(origin, origin + force * scale, color);
+            }
+        }
+        if let Some(spin_axis) = velocity.angvel.try_normalize() {
+            gizmos.line(
+                origin - spin_axis * SPIN_AXIS_HALF_LENGTH,
+                origin + spin_axis * SPIN_AXIS_HALF_LENGTH,
+                SPIN_AXIS_COLOR,
+            );
+        }
+    }
+}
+
+pub(crate) fn force_plot(
+    mut contexts: EguiContexts,
+    mut force_visualization: ResMut<ForceVisualization>,
+    force_history: Res<ForceHistory>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    egui::Window::new("forces")
+        .default_size([420.0, 260.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                for component in ForceComponent::ALL {
+                    ui.selectable_value(
+                        &mut force_visualization.plot_component,
+                        component,
+                        component.label(),
+                    );
+                }
+            });
+
+            let component = force_visualization.plot_component;
+            let series = |force: fn(&ForceSample) -> Vec3| -> egui_plot::PlotPoints {
+                force_history
+                    .samples
+                    .iter()
+                    .map(|sample| [sample.time as f64, component.of(force(sample)) as f64])
+                    .collect()
+            };
+
+            egui_plot::Plot::new("force history")
+                .legend(egui_plot::Legend::default())
+                .x_axis_label("time (s)")
+                .y_axis_label("force (N)")
+                .show(ui, |plot_ui| {
+                    let forces: [(&str, Color, fn(&ForceSample) -> Vec3); 4] = [
+                        ("gravity", GRAVITY_COLOR, |sample| sample.gravity),
+                        ("drag", DRAG_COLOR, |sample| sample.forces.drag),
+                        ("Magnus", MAGNUS_COLOR, |sample| sample.forces.magnus),
+                        ("seam-shifted wake", SSW_COLOR, |sample| sample.forces.ssw),
+                    ];
+                    for (name, color, force) in forces {
+                        plot_ui.line(
+                            egui_plot::Line::new(series(force))
+                                .name(name)
+                                .color(egui_color(color)),
+                        );
+                    }
+                });
+        });
+}
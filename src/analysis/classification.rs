@@ -0,0 +1,179 @@
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum PitchClass {
+    FourSeam,
+    Sinker,
+    Cutter,
+    Slider,
+    Sweeper,
+    Curveball,
+    Changeup,
+    Splitter,
+    Knuckleball,
+}
+
+impl PitchClass {
+    pub(crate) const ALL: [PitchClass; 9] = [
+        PitchClass::FourSeam,
+        PitchClass::Sinker,
+        PitchClass::Cutter,
+        PitchClass::Slider,
+        PitchClass::Sweeper,
+        PitchClass::Curveball,
+        PitchClass::Changeup,
+        PitchClass::Splitter,
+        PitchClass::Knuckleball,
+    ];
+
+    pub(crate) fn is_fastball(&self) -> bool {
+        matches!(
+            self,
+            PitchClass::FourSeam | PitchClass::Sinker | PitchClass::Cutter
+        )
+    }
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            PitchClass::FourSeam => "four-seam",
+            PitchClass::Sinker => "sinker",
+            PitchClass::Cutter => "cutter",
+            PitchClass::Slider => "slider",
+            PitchClass::Sweeper => "sweeper",
+            PitchClass::Curveball => "curveball",
+            PitchClass::Changeup => "changeup",
+            PitchClass::Splitter => "splitter",
+            PitchClass::Knuckleball => "knuckleball",
+        }
+    }
+}
+
+/// Typical pitch of a class. Movement is in inches, horizontal break toward the arm side,
+/// so the same centroids work for both arms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PitchCentroid {
+    pub class: PitchClass,
+    /// mph
+    pub speed: f32,
+    /// rpm
+    pub spin_rate: f32,
+    pub induced_vertical_break: f32,
+    pub horizontal_break: f32,
+}
+
+impl PitchCentroid {
+    const fn new(
+        class: PitchClass,
+        speed: f32,
+        spin_rate: f32,
+        induced_vertical_break: f32,
+        horizontal_break: f32,
+    ) -> Self {
+        Self {
+            class,
+            speed,
+            spin_rate,
+            induced_vertical_break,
+            horizontal_break,
+        }
+    }
+}
+
+/// Nearest-centroid pitch classifier. Each feature difference is divided by its scale
+/// before measuring the distance.
+#[derive(Debug, Resource, Clone, PartialEq)]
+pub(crate) struct PitchClassifier {
+    pub centroids: Vec<PitchCentroid>,
+    /// mph
+    pub speed_scale: f32,
+    /// rpm
+    pub spin_rate_scale: f32,
+    /// inches
+    pub movement_scale: f32,
+}
+
+impl Default for PitchClassifier {
+    fn default() -> Self {
+        Self {
+            centroids: vec![
+                PitchCentroid::new(PitchClass::FourSeam, 94., 2300., 16., 8.),
+                PitchCentroid::new(PitchClass::Sinker, 93., 2150., 8., 15.),
+                PitchCentroid::new(PitchClass::Cutter, 89., 2400., 9., -3.),
+                PitchCentroid::new(PitchClass::Slider, 85., 2450., 2., -6.),
+                PitchCentroid::new(PitchClass::Sweeper, 82., 2600., 1., -15.),
+                PitchCentroid::new(PitchClass::Curveball, 79., 2600., -10., -8.),
+                PitchCentroid::new(PitchClass::Changeup, 85., 1750., 6., 14.),
+                PitchCentroid::new(PitchClass::Splitter, 86., 1400., 3., 9.),
+                PitchCentroid::new(PitchClass::Knuckleball, 75., 250., 0., 0.),
+            ],
+            speed_scale: 4.,
+            spin_rate_scale: 350.,
+            movement_scale: 4.,
+        }
+    }
+}
+
+impl PitchClassifier {
+    pub(crate) fn classify(&self, pitch: &PitchParams, movement: &PitchMovement) -> PitchClass {
+        let ivb = movement.induced_vertical_break * M_TO_INCHES;
+        let hb = movement.horizontal_break * M_TO_INCHES;
+        let distance = |centroid: &PitchCentroid| {
+            ((pitch.speed - centroid.speed) / self.speed_scale).powi(2)
+                + ((pitch.spin_rate - centroid.spin_rate) / self.spin_rate_scale).powi(2)
+                + ((ivb - centroid.induced_vertical_break) / self.movement_scale).powi(2)
+                + ((hb - centroid.horizontal_break) / self.movement_scale).powi(2)
+        };
+        self.centroids
+            .iter()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+            .map_or(PitchClass::FourSeam, |centroid| centroid.class)
+    }
+
+    /// Centroid table with editable cells.
+    pub(crate) fn edit(&mut self, ui: &mut egui::Ui, units: &Units) {
+        egui::Grid::new("pitch centroids")
+            .striped(true)
+            .spacing([12.0, 4.0])
+            .show(ui, |ui| {
+                ui.strong("class");
+                ui.strong(units.speed.symbol());
+                ui.strong(units.spin.symbol());
+                ui.strong(unit_label("IVB", units.length));
+                ui.strong(unit_label("HB, + arm side", units.length));
+                ui.end_row();
+                // centroids are kept in mph, rpm and inches
+                let (mph, rpm, inches) = (SpeedUnit::Mph, SpinUnit::Rpm, LengthUnit::Inches);
+                for centroid in self.centroids.iter_mut() {
+                    ui.label(centroid.class.label());
+                    unit_drag(ui, &mut centroid.speed, mph, units.speed, 0.5);
+                    unit_drag(ui, &mut centroid.spin_rate, rpm, units.spin, 10.);
+                    unit_drag(
+                        ui,
+                        &mut centroid.induced_vertical_break,
+                        inches,
+                        units.length,
+                        0.5,
+                    );
+                    unit_drag(
+                        ui,
+                        &mut centroid.horizontal_break,
+                        inches,
+                        units.length,
+                        0.5,
+                    );
+                    ui.end_row();
+                }
+                ui.label("scale");
+                unit_drag(ui, &mut self.speed_scale, mph, units.speed, 0.1);
+                unit_drag(ui, &mut self.spin_rate_scale, rpm, units.spin, 10.);
+                unit_drag(ui, &mut self.movement_scale, inches, units.length, 0.1);
+                self.speed_scale = self.speed_scale.clamp(0.1, 50.);
+                self.spin_rate_scale = self.spin_rate_scale.clamp(1., 2000.);
+                self.movement_scale = self.movement_scale.clamp(0.1, 50.);
+                ui.end_row();
+            });
+        if ui.button("Reset").clicked() {
+            *self = Self::default();
+        }
+    }
+}
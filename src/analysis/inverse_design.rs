@@ -0,0 +1,307 @@
+use crate::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+use std::f32::consts::PI;
+
+const SOLVER_ITERATIONS: usize = 80;
+/// number of coarse candidates refined by the simplex solver
+const SOLVER_STARTS: usize = 3;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MovementTarget {
+    /// induced vertical break in inches
+    pub induced_vertical_break: f32,
+    /// horizontal break in inches, positive toward the arm side
+    pub horizontal_break: f32,
+    /// mph
+    pub speed: f32,
+}
+
+impl Default for MovementTarget {
+    fn default() -> Self {
+        Self {
+            induced_vertical_break: 16.,
+            horizontal_break: 8.,
+            speed: 94.,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DesignSolution {
+    pub pitch: PitchParams,
+    pub movement: PitchMovement,
+}
+
+/// "Design from movement": solves for spin parameters that produce a target movement.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct InverseDesign {
+    pub open: bool,
+    pub target: MovementTarget,
+    pub preset_name: String,
+    pub solution: Option<DesignSolution>,
+    task: Option<Task<Option<DesignSolution>>>,
+}
+
+impl InverseDesign {
+    pub(crate) fn is_solving(&self) -> bool {
+        self.task.is_some()
+    }
+}
+
+/// Maps a point in the solver's search space to pitch parameters.
+/// Each coordinate covers its full range over [0, 1].
+fn decode(x: &[f32], base: &PitchParams, gyro_pole: GyroPole) -> PitchParams {
+    PitchParams {
+        spin_rate: 500. + 2500. * x[0].clamp(0., 1.),
+        spin_efficiency: x[1].clamp(0., 1.),
+        tilt: tilt_from_clock_minutes(720. * x[2]),
+        seam_y_angle: (2. * PI * x[3]).rem_euclid(2. * PI) - PI,
+        seam_z_angle: (2. * PI * x[4]).rem_euclid(2. * PI) - PI,
+        gyro_pole,
+        ..*base
+    }
+}
+
+fn encode(pitch: &PitchParams) -> Vec<f32> {
+    vec![
+        (pitch.spin_rate - 500.) / 2500.,
+        pitch.spin_efficiency,
+        tilt_to_clock_minutes(pitch.tilt) / 720.,
+        (pitch.seam_y_angle + PI) / (2. * PI),
+        (pitch.seam_z_angle + PI) / (2. * PI),
+    ]
+}
+
+/// Searches spin rate, spin efficiency, tilt, gyro pole and seam orientation
+/// for a pitch that moves like `target`.
+pub(crate) fn solve_for_movement(
+    base: PitchParams,
+    target: MovementTarget,
+    field: FieldGeometry,
+    environment: AeroEnvironment,
+    profile: AeroProfile,
+    effects: AeroEffects,
+) -> Option<DesignSolution> {
+    let base = PitchParams {
+        speed: target.speed,
+        ..base
+    };
+    let direction = analysis_direction(&base, &field);
+    // spin doesn't change the spinless path, so it is simulated once
+    let spinless_location =
+        spinless_plate_location(&base, direction, &field, &environment, &profile, &effects)?;
+
+    let movement_of = |pitch: &PitchParams| -> Option<PitchMovement> {
+        let location = simulate_pitch(pitch, direction, &field, &environment, &profile, &effects)
+            .plate_location()?;
+        Some(PitchMovement::between(
+            location,
+            spinless_location,
+            pitch.pitching_arm,
+        ))
+    };
+    let cost_of = |x: &[f32], gyro_pole: GyroPole| -> f32 {
+        // keep the bounded coordinates inside their range
+        let out_of_bounds: f32 = x[..2].iter().map(|v| (v - v.clamp(0., 1.)).powi(2)).sum();
+        match movement_of(&decode(x, &base, gyro_pole)) {
+            Some(movement) => {
+                ((movement.induced_vertical_break * M_TO_INCHES - target.induced_vertical_break)
+                    .powi(2)
+                    + (movement.horizontal_break * M_TO_INCHES - target.horizontal_break).powi(2))
+                    + 1000. * out_of_bounds
+            }
+            None => f32::MAX,
+        }
+    };
+
+    // coarse sweep over the clock and spin efficiency
+    let mut candidates = vec![];
+    for gyro_pole in [GyroPole::Left, GyroPole::Right] {
+        for half_hour in 0..24 {
+            for spin_efficiency in [0.4, 0.7, 1.0] {
+                let mut x = encode(&base);
+                x[0] = (2200. - 500.) / 2500.;
+                x[1] = spin_efficiency;
+                x[2] = half_hour as f32 / 24.;
+                candidates.push((x.clone(), gyro_pole, cost_of(&x, gyro_pole)));
+            }
+        }
+    }
+    candidates.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+    let (x, gyro_pole, _) = candidates
+        .into_iter()
+        .take(SOLVER_STARTS)
+        .map(|(x, gyro_pole, _)| {
+            let (x, cost) = nelder_mead(|x| cost_of(x, gyro_pole), &x, 0.1, SOLVER_ITERATIONS);
+            (x, gyro_pole, cost)
+        })
+        .min_by(|a, b| a.2.total_cmp(&b.2))?;
+
+    let pitch = decode(&x, &base, gyro_pole);
+    Some(DesignSolution {
+        pitch,
+        movement: movement_of(&pitch)?,
+    })
+}
+
+pub(crate) fn poll_inverse_design(mut inverse_design: ResMut<InverseDesign>) {
+    let Some(task) = inverse_design.task.as_mut() else {
+        return;
+    };
+    if let Some(solution) = block_on(future::poll_once(task)) {
+        inverse_design.task = None;
+        inverse_design.solution = solution;
+        if solution.is_none() {
+            warn!("no pitch found for the target movement");
+        }
+    }
+}
+
+pub(crate) fn inverse_design_window(
+    mut contexts: EguiContexts,
+    mut inverse_design: ResMut<InverseDesign>,
+    mut selected_pitch_parameters: ResMut<SelectedPitchParameters>,
+    mut pitch_presets: ResMut<PitchPresets>,
+    field_geometry: Res<FieldGeometry>,
+    aero_environment: Res<AeroEnvironment>,
+    aero_profile: Res<AeroProfile>,
+    aero_effects: Res<AeroEffects>,
+    units: Res<Units>,
+) {
+    let ctx = contexts.ctx_mut();
+    let inverse_design = inverse_design.as_mut();
+
+    let mut open = inverse_design.open;
+    egui::Window::new("design from movement")
+        .open(&mut open)
+        .show(ctx, |ui| {
+            egui::Grid::new("movement target")
+                .spacing([20.0, 10.0])
+                .show(ui, |ui| {
+                    // the target is kept in mph and inches
+                    let target = &mut inverse_design.target;
+                    ui.label(unit_label("speed", units.speed));
+                    let mut speed = target.speed * MPH_TO_MPS;
+                    if unit_slider(
+                        ui,
+                        &mut speed,
+                        60. * MPH_TO_MPS..=105. * MPH_TO_MPS,
+                        units.speed,
+                    )
+                    .changed()
+                    {
+                        target.speed = speed / MPH_TO_MPS;
+                    }
+                    ui.end_row();
+
+                    let max_break = 25. / M_TO_INCHES;
+                    ui.label(unit_label("induced vertical break", units.length));
+                    let mut ivb = target.induced_vertical_break / M_TO_INCHES;
+                    if unit_slider(ui, &mut ivb, -max_break..=max_break, units.length).changed() {
+                        target.induced_vertical_break = ivb * M_TO_INCHES;
+                    }
+                    ui.end_row();
+
+                    ui.label(unit_label("horizontal break, + arm side", units.length));
+                    let mut hb = target.horizontal_break / M_TO_INCHES;
+                    if unit_slider(ui, &mut hb, -max_break..=max_break, units.length).changed() {
+                        target.horizontal_break = hb * M_TO_INCHES;
+                    }
+                    ui.end_row();
+                });
+
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                let solve =
+                    ui.add_enabled(!inverse_design.is_solving(), egui::Button::new("Solve"));
+                if solve.clicked() {
+                    let base = selected_pitch_parameters.0;
+                    let target = inverse_design.target;
+                    let field = *field_geometry;
+                    let environment = *aero_environment;
+                    let profile = *aero_profile;
+                    let effects = *aero_effects;
+                    inverse_design.task = Some(AsyncComputeTaskPool::get().spawn(async move {
+                        solve_for_movement(base, target, field, environment, profile, effects)
+                    }));
+                }
+                if inverse_design.is_solving() {
+                    ui.spinner();
+                }
+            });
+
+            let Some(solution) = inverse_design.solution else {
+                return;
+            };
+            ui.add_space(10.0);
+            let pitch = solution.pitch;
+            let (hr, min) = pitch.tilt.to_hour_minutes();
+            egui::Grid::new("design solution")
+                .spacing([20.0, 5.0])
+                .show(ui, |ui| {
+                    ui.label("spin");
+                    ui.label(units.spin.format(pitch.spin_rate / RADS_TO_RPM, 0));
+                    ui.end_row();
+                    ui.label("spin efficiency (%)");
+                    ui.label(format!("{:.0}", pitch.spin_efficiency * 100.));
+                    ui.end_row();
+                    ui.label("tilt");
+                    ui.label(format!("{}:{:02}", hr, min));
+                    ui.end_row();
+                    ui.label("gyro pole");
+                    ui.label(format!("{:?}", pitch.gyro_pole));
+                    ui.end_row();
+                    ui.label("seam angles (°)");
+                    ui.label(format!(
+                        "y {:.0}, z {:.0}",
+                        pitch.seam_y_angle.to_degrees(),
+                        pitch.seam_z_angle.to_degrees()
+                    ));
+                    ui.end_row();
+                    ui.label(unit_label("movement", units.length));
+                    ui.label(format!(
+                        "IVB {:.1}, HB {:.1}",
+                        units
+                            .length
+                            .from_base(solution.movement.induced_vertical_break),
+                        units.length.from_base(solution.movement.horizontal_break)
+                    ));
+                    ui.end_row();
+                });
+
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                ui.label("preset name");
+                ui.text_edit_singleline(&mut inverse_design.preset_name);
+                if ui.button("Apply as preset").clicked() {
+                    let name = if inverse_design.preset_name.is_empty() {
+                        format!(
+                            "{} {:.0}/{:.0}",
+                            units.speed.format(pitch.speed * MPH_TO_MPS, 0),
+                            convert(
+                                inverse_design.target.induced_vertical_break,
+                                LengthUnit::Inches,
+                                units.length
+                            ),
+                            convert(
+                                inverse_design.target.horizontal_break,
+                                LengthUnit::Inches,
+                                units.length
+                            )
+                        )
+                    } else {
+                        inverse_design.preset_name.clone()
+                    };
+                    // keep aiming where the pitcher was aiming
+                    let params = PitchParams {
+                        direction: selected_pitch_parameters.0.direction,
+                        ..pitch
+                    };
+                    selected_pitch_parameters.0 = params;
+                    pitch_presets.save(PitchPreset { name, params });
+                }
+            });
+        });
+    inverse_design.open = open;
+}
@@ -0,0 +1,149 @@
+mod classification;
+mod inverse_design;
+mod movement;
+mod pitch_import;
+mod pitch_log;
+mod seam_optimizer;
+mod sensitivity;
+mod solver;
+mod stuff;
+mod tunneling;
+
+use crate::prelude::*;
+use bevy::ecs::system::SystemParam;
+use classification::*;
+use inverse_design::*;
+use pitch_import::*;
+use pitch_log::*;
+use seam_optimizer::*;
+use sensitivity::*;
+use stuff::*;
+use tunneling::*;
+
+pub(crate) mod prelude {
+    pub(crate) use super::*;
+    pub(crate) use classification::*;
+    pub(crate) use inverse_design::*;
+    pub(crate) use movement::*;
+    pub(crate) use pitch_import::*;
+    pub(crate) use pitch_log::*;
+    pub(crate) use seam_optimizer::*;
+    pub(crate) use sensitivity::*;
+    pub(crate) use solver::*;
+    pub(crate) use stuff::*;
+    pub(crate) use tunneling::*;
+}
+
+/// Resources of every analysis tool, for menus that open them.
+#[derive(SystemParam)]
+pub(crate) struct AnalysisTools<'w> {
+    inverse_design: ResMut<'w, InverseDesign>,
+    seam_optimizer: ResMut<'w, SeamOptimizer>,
+    sensitivity_sweep: ResMut<'w, SensitivitySweep>,
+    tunneling_analysis: ResMut<'w, TunnelingAnalysis>,
+    pitch_import: ResMut<'w, PitchImport>,
+    pitch_report: ResMut<'w, PitchReport>,
+}
+
+impl AnalysisTools<'_> {
+    /// Checkboxes opening and closing each tool window.
+    pub(crate) fn toggles(&mut self, ui: &mut egui::Ui) {
+        ui.checkbox(&mut self.inverse_design.open, "design from movement");
+        ui.checkbox(&mut self.seam_optimizer.open, "seam orientation");
+        ui.checkbox(&mut self.sensitivity_sweep.open, "sensitivity sweep");
+        ui.checkbox(&mut self.tunneling_analysis.open, "tunneling");
+        ui.checkbox(&mut self.pitch_import.open, "import pitches");
+        ui.checkbox(&mut self.pitch_report.open, "pitch report");
+    }
+}
+
+/// Pitch design and analysis tools, each shown in its own window.
+#[derive(Debug)]
+pub(crate) struct AnalysisPlugin<T: GameScene> {
+    pub scene: T,
+}
+
+impl<T: GameScene> Plugin for AnalysisPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InverseDesign>()
+            .init_resource::<SeamOptimizer>()
+            .init_resource::<SensitivitySweep>()
+            .init_resource::<TunnelingAnalysis>()
+            .init_resource::<PitchImport>()
+            .init_resource::<PitchClassifier>()
+            .init_resource::<PitchLog>()
+            .init_resource::<PitchReport>()
+            .init_resource::<StuffModel>();
+
+        app.add_systems(
+            OnEnter(self.scene.clone()),
+            (
+                reset_resource::<InverseDesign>,
+                reset_resource::<SeamOptimizer>,
+                reset_resource::<SensitivitySweep>,
+                reset_resource::<TunnelingAnalysis>,
+                reset_resource::<PitchImport>,
+                reset_resource::<PitchLog>,
+                reset_resource::<PitchReport>,
+            )
+                .in_set(GameScenesSet::OnEnterSet(self.scene.clone())),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                poll_inverse_design,
+                inverse_design_window.run_if(|tool: Res<InverseDesign>| tool.open),
+            )
+                .chain()
+                .in_set(GameScenesSet::UpdateSet(self.scene.clone())),
+        );
+        app.add_systems(
+            Update,
+            (
+                poll_seam_optimizer,
+                seam_optimizer_window.run_if(|tool: Res<SeamOptimizer>| tool.open),
+            )
+                .chain()
+                .in_set(GameScenesSet::UpdateSet(self.scene.clone())),
+        );
+        app.add_systems(
+            Update,
+            (
+                poll_sensitivity_sweep,
+                (sensitivity_sweep_window, draw_sensitivity_fan)
+                    .run_if(|tool: Res<SensitivitySweep>| tool.open),
+            )
+                .chain()
+                .in_set(GameScenesSet::UpdateSet(self.scene.clone())),
+        );
+        app.add_systems(
+            Update,
+            (
+                tunneling_window.run_if(|tool: Res<TunnelingAnalysis>| tool.open),
+                sync_tunnel_paths::<T>,
+            )
+                .chain()
+                .in_set(GameScenesSet::UpdateSet(self.scene.clone())),
+        );
+        app.add_systems(
+            Update,
+            (
+                poll_pitch_import,
+                pitch_import_window.run_if(|tool: Res<PitchImport>| tool.open),
+            )
+                .chain()
+                .in_set(GameScenesSet::UpdateSet(self.scene.clone())),
+        );
+        app.add_systems(
+            Update,
+            (
+                (log_thrown_pitches, record_plate_spin).chain(),
+                (classify_presets, pitch_report_window)
+                    .chain()
+                    .run_if(|tool: Res<PitchReport>| tool.open),
+            )
+                .in_set(GameScenesSet::UpdateSet(self.scene.clone())),
+        );
+    }
+}
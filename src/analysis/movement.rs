@@ -0,0 +1,110 @@
+use crate::prelude::*;
+
+/// height the analysis tools aim at, roughly the middle of the zone
+pub(crate) const ANALYSIS_AIM_HEIGHT: f32 = 0.75;
+
+/// Pitch movement at the front of home plate, relative to a spinless ball thrown
+/// the same way, in meters.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct PitchMovement {
+    pub induced_vertical_break: f32,
+    /// positive toward the pitcher's arm side
+    pub horizontal_break: f32,
+}
+
+impl PitchMovement {
+    /// Movement of `location` relative to where the spinless ball crossed the plate.
+    pub(crate) fn between(
+        location: Vec3,
+        spinless_location: Vec3,
+        pitching_arm: PitchingArm,
+    ) -> Self {
+        let arm_side = match pitching_arm {
+            PitchingArm::Righty => 1.,
+            PitchingArm::Lefty => -1.,
+        };
+        Self {
+            induced_vertical_break: location.y - spinless_location.y,
+            horizontal_break: (location.x - spinless_location.x) * arm_side,
+        }
+    }
+}
+
+/// Direction the analysis tools throw in, from the release point to the middle of the plate.
+pub(crate) fn analysis_direction(pitch: &PitchParams, field: &FieldGeometry) -> Vec3 {
+    (Vec3::new(0., ANALYSIS_AIM_HEIGHT, DEFAULT_FRONT_PANEL_POS_Z) - pitch.release_point(field))
+        .normalize()
+}
+
+/// Plate location of the same pitch without any spin-driven forces.
+pub(crate) fn spinless_plate_location(
+    pitch: &PitchParams,
+    direction: Vec3,
+    field: &FieldGeometry,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+    effects: &AeroEffects,
+) -> Option<Vec3> {
+    let spinless_effects = AeroEffects {
+        magnus_on: false,
+        ssw_on: false,
+        ..*effects
+    };
+    simulate_pitch(
+        pitch,
+        direction,
+        field,
+        environment,
+        profile,
+        &spinless_effects,
+    )
+    .plate_location()
+}
+
+/// How a pitch arrives at the front of home plate.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PitchMeasurement {
+    pub plate_location: Vec3,
+    pub movement: PitchMovement,
+    /// angular velocity in rad/s
+    pub release_spin: Vec3,
+}
+
+/// Measures the pitch thrown in `direction`.
+pub(crate) fn measure_pitch(
+    pitch: &PitchParams,
+    direction: Vec3,
+    field: &FieldGeometry,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+    effects: &AeroEffects,
+) -> Option<PitchMeasurement> {
+    let trajectory = simulate_pitch(pitch, direction, field, environment, profile, effects);
+    let plate_location = trajectory.plate_location()?;
+    let spinless_location =
+        spinless_plate_location(pitch, direction, field, environment, profile, effects)?;
+    Some(PitchMeasurement {
+        plate_location,
+        movement: PitchMovement::between(plate_location, spinless_location, pitch.pitching_arm),
+        release_spin: pitch.release_spin(),
+    })
+}
+
+/// Movement of the pitch thrown at the middle of the plate.
+pub(crate) fn measure_movement(
+    pitch: &PitchParams,
+    field: &FieldGeometry,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+    effects: &AeroEffects,
+) -> Option<PitchMovement> {
+    measure_pitch(
+        pitch,
+        analysis_direction(pitch, field),
+        field,
+        environment,
+        profile,
+        effects,
+    )
+    .map(|measurement| measurement.movement)
+}
@@ -0,0 +1,719 @@
+use crate::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+use std::collections::BTreeMap;
+
+/// Statcast's `release_pos_y` and extension are measured on an MLB field,
+/// so release distances are taken from home plate on a regulation field
+const STATCAST_PITCHING_DISTANCE: f32 = REGULATION_PITCHING_DISTANCE;
+/// distance from home plate, in meters, at which Statcast's `vx0`, `vy0` and `vz0` are measured
+const STATCAST_VELOCITY_DISTANCE: f32 = 50. / M_TO_FEET;
+/// times the aim is corrected toward the recorded plate location
+const AIM_ITERATIONS: usize = 4;
+
+/// A CSV column and the factor converting its values to meters, rpm, mph or degrees.
+/// Statcast and Trackman names are both accepted.
+struct Column {
+    names: &'static [&'static str],
+    scale: f32,
+}
+
+const SPEED: Column = Column {
+    names: &["release_speed", "RelSpeed"],
+    scale: 1.,
+};
+const SPIN_RATE: Column = Column {
+    names: &["release_spin_rate", "SpinRate"],
+    scale: 1.,
+};
+const SPIN_AXIS: Column = Column {
+    names: &["spin_axis", "SpinAxis"],
+    scale: 1.,
+};
+const SPIN_EFFICIENCY: Column = Column {
+    names: &["spin_efficiency"],
+    scale: 1.,
+};
+const RELEASE_SIDE: Column = Column {
+    names: &["release_pos_x", "RelSide"],
+    scale: 1. / M_TO_FEET,
+};
+const RELEASE_HEIGHT: Column = Column {
+    names: &["release_pos_z", "RelHeight"],
+    scale: 1. / M_TO_FEET,
+};
+const RELEASE_DISTANCE: Column = Column {
+    names: &["release_pos_y"],
+    scale: 1. / M_TO_FEET,
+};
+const EXTENSION: Column = Column {
+    names: &["release_extension", "Extension"],
+    scale: 1. / M_TO_FEET,
+};
+const VX0: Column = Column {
+    names: &["vx0"],
+    scale: 1. / M_TO_FEET,
+};
+const VY0: Column = Column {
+    names: &["vy0"],
+    scale: 1. / M_TO_FEET,
+};
+const VZ0: Column = Column {
+    names: &["vz0"],
+    scale: 1. / M_TO_FEET,
+};
+const AX: Column = Column {
+    names: &["ax"],
+    scale: 1. / M_TO_FEET,
+};
+const AY: Column = Column {
+    names: &["ay"],
+    scale: 1. / M_TO_FEET,
+};
+const AZ: Column = Column {
+    names: &["az"],
+    scale: 1. / M_TO_FEET,
+};
+const PFX_X_FT: Column = Column {
+    names: &["pfx_x"],
+    scale: 1. / M_TO_FEET,
+};
+const PFX_X_IN: Column = Column {
+    names: &["HorzBreak"],
+    scale: 1. / M_TO_INCHES,
+};
+const PFX_Z_FT: Column = Column {
+    names: &["pfx_z"],
+    scale: 1. / M_TO_FEET,
+};
+const PFX_Z_IN: Column = Column {
+    names: &["InducedVertBreak"],
+    scale: 1. / M_TO_INCHES,
+};
+const PLATE_SIDE: Column = Column {
+    names: &["plate_x", "PlateLocSide"],
+    scale: 1. / M_TO_FEET,
+};
+const PLATE_HEIGHT: Column = Column {
+    names: &["plate_z", "PlateLocHeight"],
+    scale: 1. / M_TO_FEET,
+};
+const PITCH_TYPE: &[&str] = &["pitch_type", "TaggedPitchType", "AutoPitchType"];
+const PITCHER: &[&str] = &["player_name", "Pitcher"];
+const THROWS: &[&str] = &["p_throws", "PitcherThrows"];
+
+/// A pitch read from a tracking data export, in bevy coordinates.
+#[derive(Debug, Clone)]
+pub(crate) struct RecordedPitch {
+    pub pitcher: String,
+    pub pitch_type: String,
+    pub params: PitchParams,
+    pub release_point: Vec3,
+    /// velocity at the release point, when the export has it
+    pub release_velocity: Option<Vec3>,
+    pub movement: Option<PitchMovement>,
+    pub plate_location: Option<Vec3>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ImportedPitch {
+    pub recorded: RecordedPitch,
+    pub movement: Option<PitchMovement>,
+    pub plate_location: Option<Vec3>,
+    /// whether the pitch was aimed at its recorded plate location,
+    /// which makes its plate location error meaningless
+    pub aimed: bool,
+}
+
+impl ImportedPitch {
+    /// Simulated minus recorded movement, in meters.
+    pub(crate) fn movement_error(&self) -> Option<Vec2> {
+        let (simulated, recorded) = (self.movement?, self.recorded.movement?);
+        Some(Vec2::new(
+            simulated.horizontal_break - recorded.horizontal_break,
+            simulated.induced_vertical_break - recorded.induced_vertical_break,
+        ))
+    }
+
+    pub(crate) fn plate_location_error(&self) -> Option<f32> {
+        if self.aimed {
+            return None;
+        }
+        Some(self.plate_location?.distance(self.recorded.plate_location?))
+    }
+}
+
+/// Spin axis in degrees, 180 being pure backspin, as a tilt on the clock face.
+fn tilt_from_spin_axis(spin_axis: f32) -> Tilt {
+    tilt_from_clock_minutes((spin_axis - 180.) * 2.)
+}
+
+/// Velocity at `release_distance` from home plate of a pitch measured with `velocity` at
+/// `STATCAST_VELOCITY_DISTANCE`, in Statcast's axes. Statcast fits each pitch with a constant
+/// `acceleration`; without one, gravity alone is assumed, which leaves out the drag and
+/// movement of the last few feet before the measurement.
+fn statcast_release_velocity(
+    velocity: Vec3,
+    acceleration: Option<Vec3>,
+    release_distance: f32,
+) -> Vec3 {
+    let acceleration = acceleration.unwrap_or(Vec3::new(0., 0., GRAVITY.y));
+    // solves y(t) = 50 ft + vy t + ay t^2 / 2 for the release distance; the pitch moves
+    // toward the plate, so the root is the one before the measurement, t < 0
+    let offset = STATCAST_VELOCITY_DISTANCE - release_distance;
+    let discriminant = (velocity.y.powi(2) - 2. * acceleration.y * offset).max(0.);
+    let t = -2. * offset / (velocity.y - discriminant.sqrt());
+    velocity + acceleration * t
+}
+
+/// Reads Statcast or Trackman pitches. Tracking data measures sideways positions from the
+/// catcher's view, toward first base, which is -x here.
+pub(crate) fn read_pitch_csv(
+    reader: impl std::io::Read,
+    assumed_spin_efficiency: f32,
+) -> Result<Vec<RecordedPitch>, Error> {
+    let mut csv_reader = csv::Reader::from_reader(reader);
+    let headers = csv_reader
+        .headers()
+        .map_err(|e| Error::GenericError(e.to_string()))?
+        .clone();
+    let find = |names: &[&str]| headers.iter().position(|header| names.contains(&header));
+    let columns: BTreeMap<&str, (usize, f32)> = [
+        ("speed", &SPEED),
+        ("spin_rate", &SPIN_RATE),
+        ("spin_axis", &SPIN_AXIS),
+        ("spin_efficiency", &SPIN_EFFICIENCY),
+        ("release_side", &RELEASE_SIDE),
+        ("release_height", &RELEASE_HEIGHT),
+        ("release_distance", &RELEASE_DISTANCE),
+        ("extension", &EXTENSION),
+        ("vx0", &VX0),
+        ("vy0", &VY0),
+        ("vz0", &VZ0),
+        ("ax", &AX),
+        ("ay", &AY),
+        ("az", &AZ),
+        ("pfx_x", &PFX_X_FT),
+        ("pfx_x", &PFX_X_IN),
+        ("pfx_z", &PFX_Z_FT),
+        ("pfx_z", &PFX_Z_IN),
+        ("plate_side", &PLATE_SIDE),
+        ("plate_height", &PLATE_HEIGHT),
+    ]
+    .into_iter()
+    .filter_map(|(key, column)| Some((key, (find(column.names)?, column.scale))))
+    .collect();
+    for required in ["speed", "spin_rate", "spin_axis"] {
+        if !columns.contains_key(required) {
+            return Err(Error::GenericError(format!(
+                "missing column for {}",
+                required
+            )));
+        }
+    }
+    let (pitch_type, pitcher, throws) = (find(PITCH_TYPE), find(PITCHER), find(THROWS));
+
+    let mut pitches = vec![];
+    for record in csv_reader.records() {
+        let record = record.map_err(|e| Error::GenericError(e.to_string()))?;
+        let text = |index: Option<usize>| {
+            index
+                .and_then(|index| record.get(index))
+                .unwrap_or_default()
+                .to_string()
+        };
+        let value = |key: &str| {
+            let (index, scale) = columns.get(key)?;
+            record
+                .get(*index)?
+                .trim()
+                .parse::<f32>()
+                .ok()
+                .map(|v| v * scale)
+        };
+        // rows with missing measurements are skipped
+        let (Some(speed), Some(spin_rate), Some(spin_axis)) =
+            (value("speed"), value("spin_rate"), value("spin_axis"))
+        else {
+            continue;
+        };
+
+        let pitching_arm = match text(throws).chars().next() {
+            Some('L') => PitchingArm::Lefty,
+            _ => PitchingArm::Righty,
+        };
+        let arm_side = match pitching_arm {
+            PitchingArm::Righty => 1.,
+            PitchingArm::Lefty => -1.,
+        };
+        let default_release = FieldPreset::Mlb.geometry().release_point(pitching_arm);
+        let release_point = Vec3::new(
+            value("release_side").map_or(default_release.x, |x| -x),
+            value("release_height").unwrap_or(default_release.y),
+            value("release_distance")
+                .or(value("extension").map(|extension| STATCAST_PITCHING_DISTANCE - extension))
+                .unwrap_or(default_release.z),
+        );
+        let acceleration = match (value("ax"), value("ay"), value("az")) {
+            (Some(ax), Some(ay), Some(az)) => Some(Vec3::new(ax, ay, az)),
+            _ => None,
+        };
+        let release_velocity = match (value("vx0"), value("vy0"), value("vz0")) {
+            (Some(vx), Some(vy), Some(vz)) => {
+                let velocity =
+                    statcast_release_velocity(Vec3::new(vx, vy, vz), acceleration, release_point.z);
+                Some(Vec3::new(-velocity.x, velocity.z, velocity.y))
+            }
+            _ => None,
+        };
+        let movement = match (value("pfx_x"), value("pfx_z")) {
+            (Some(pfx_x), Some(pfx_z)) => Some(PitchMovement {
+                induced_vertical_break: pfx_z,
+                horizontal_break: -pfx_x * arm_side,
+            }),
+            _ => None,
+        };
+        let plate_location = match (value("plate_side"), value("plate_height")) {
+            (Some(x), Some(y)) => Some(Vec3::new(-x, y, DEFAULT_FRONT_PANEL_POS_Z)),
+            _ => None,
+        };
+        let spin_efficiency = value("spin_efficiency")
+            .map(|efficiency| {
+                if efficiency > 1. {
+                    efficiency / 100.
+                } else {
+                    efficiency
+                }
+            })
+            .unwrap_or(assumed_spin_efficiency);
+
+        pitches.push(RecordedPitch {
+            pitcher: text(pitcher),
+            pitch_type: text(pitch_type),
+            params: PitchParams {
+                pitching_arm,
+                speed,
+                spin_rate,
+                spin_efficiency: spin_efficiency.clamp(0., 1.),
+                tilt: tilt_from_spin_axis(spin_axis),
+                ..SelectedPitchParameters::default().0
+            },
+            release_point,
+            release_velocity,
+            movement,
+            plate_location,
+        });
+    }
+    Ok(pitches)
+}
+
+/// Flies a recorded pitch. Without a measured release velocity the pitch is aimed
+/// so that it crosses the plate where it was recorded.
+pub(crate) fn simulate_recorded_pitch(
+    recorded: &RecordedPitch,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+    effects: &AeroEffects,
+) -> ImportedPitch {
+    let params = &recorded.params;
+    let fly = |direction: Vec3, effects: &AeroEffects| {
+        simulate_flight(
+            recorded.release_point,
+            params.release_state(direction),
+            environment,
+            profile,
+            effects,
+        )
+    };
+
+    let (direction, aimed) = match (recorded.release_velocity, recorded.plate_location) {
+        (Some(velocity), _) => (velocity.normalize(), false),
+        (None, Some(target)) => {
+            let mut aim = target;
+            let mut direction = (aim - recorded.release_point).normalize();
+            for _ in 0..AIM_ITERATIONS {
+                let Some(location) = fly(direction, effects).plate_location() else {
+                    break;
+                };
+                aim += target - location;
+                direction = (aim - recorded.release_point).normalize();
+            }
+            (direction, true)
+        }
+        (None, None) => (
+            (Vec3::new(0., ANALYSIS_AIM_HEIGHT, DEFAULT_FRONT_PANEL_POS_Z)
+                - recorded.release_point)
+                .normalize(),
+            false,
+        ),
+    };
+
+    let plate_location = fly(direction, effects).plate_location();
+    let spinless_effects = AeroEffects {
+        magnus_on: false,
+        ssw_on: false,
+        ..*effects
+    };
+    let spinless_location = fly(direction, &spinless_effects).plate_location();
+    let movement = match (plate_location, spinless_location) {
+        (Some(location), Some(spinless_location)) => Some(PitchMovement::between(
+            location,
+            spinless_location,
+            params.pitching_arm,
+        )),
+        _ => None,
+    };
+
+    ImportedPitch {
+        recorded: recorded.clone(),
+        movement,
+        plate_location,
+        aimed,
+    }
+}
+
+/// Average pitch of each pitcher and pitch type.
+pub(crate) fn arsenal_presets(pitches: &[ImportedPitch]) -> Vec<PitchPreset> {
+    let mut groups: BTreeMap<(String, String), Vec<&PitchParams>> = BTreeMap::new();
+    for pitch in pitches {
+        let recorded = &pitch.recorded;
+        groups
+            .entry((recorded.pitcher.clone(), recorded.pitch_type.clone()))
+            .or_default()
+            .push(&recorded.params);
+    }
+    groups
+        .into_iter()
+        .map(|((pitcher, pitch_type), params)| {
+            let n = params.len() as f32;
+            let mean = |f: fn(&PitchParams) -> f32| params.iter().map(|p| f(p)).sum::<f32>() / n;
+            // tilt wraps around the clock, so it's averaged as a direction
+            let tilt_direction: Vec2 = params
+                .iter()
+                .map(|p| {
+                    Vec2::from_angle(tilt_to_clock_minutes(p.tilt) / 720. * std::f32::consts::TAU)
+                })
+                .sum();
+            let tilt_minutes = tilt_direction.to_angle() / std::f32::consts::TAU * 720.;
+            PitchPreset {
+                name: format!("{} {}", pitcher, pitch_type).trim().to_string(),
+                params: PitchParams {
+                    speed: mean(|p| p.speed),
+                    spin_rate: mean(|p| p.spin_rate),
+                    spin_efficiency: mean(|p| p.spin_efficiency),
+                    tilt: tilt_from_clock_minutes(tilt_minutes),
+                    ..*params[0]
+                },
+            }
+        })
+        .collect()
+}
+
+fn load_pitch_csv(
+    path: &str,
+    assumed_spin_efficiency: f32,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+    effects: &AeroEffects,
+) -> Result<Vec<ImportedPitch>, Error> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| Error::GenericError(format!("couldn't open {}: {}", path, e)))?;
+    let recorded = read_pitch_csv(file, assumed_spin_efficiency)?;
+    Ok(recorded
+        .iter()
+        .map(|pitch| simulate_recorded_pitch(pitch, environment, profile, effects))
+        .collect())
+}
+
+/// Loads tracking data exports and compares the flight model against them.
+#[derive(Debug, Resource)]
+pub(crate) struct PitchImport {
+    pub open: bool,
+    pub path: String,
+    pub assumed_spin_efficiency: f32,
+    pub result: Option<Result<Vec<ImportedPitch>, Error>>,
+    task: Option<Task<Result<Vec<ImportedPitch>, Error>>>,
+}
+
+impl Default for PitchImport {
+    fn default() -> Self {
+        Self {
+            open: false,
+            path: String::new(),
+            assumed_spin_efficiency: 0.9,
+            result: None,
+            task: None,
+        }
+    }
+}
+
+pub(crate) fn poll_pitch_import(mut pitch_import: ResMut<PitchImport>) {
+    let Some(task) = pitch_import.task.as_mut() else {
+        return;
+    };
+    if let Some(result) = block_on(future::poll_once(task)) {
+        pitch_import.task = None;
+        pitch_import.result = Some(result);
+    }
+}
+
+fn format_length(value: Option<f32>, unit: LengthUnit) -> String {
+    value.map_or("-".to_string(), |value| {
+        format!("{:.1}", unit.from_base(value))
+    })
+}
+
+pub(crate) fn pitch_import_window(
+    mut contexts: EguiContexts,
+    mut pitch_import: ResMut<PitchImport>,
+    mut pitch_presets: ResMut<PitchPresets>,
+    aero_environment: Res<AeroEnvironment>,
+    aero_profile: Res<AeroProfile>,
+    aero_effects: Res<AeroEffects>,
+    units: Res<Units>,
+) {
+    let ctx = contexts.ctx_mut();
+    let pitch_import = pitch_import.as_mut();
+
+    let mut open = pitch_import.open;
+    egui::Window::new("import pitches")
+        .open(&mut open)
+        .default_size([560.0, 420.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("CSV file");
+                ui.text_edit_singleline(&mut pitch_import.path);
+            });
+            ui.horizontal(|ui| {
+                ui.label("spin efficiency when not recorded");
+                egui::Slider::new(&mut pitch_import.assumed_spin_efficiency, 0.0_f32..=1.0_f32)
+                    .ui(ui);
+            });
+            ui.horizontal(|ui| {
+                // the web build has no file system to open the path on
+                let can_open_files = cfg!(not(target_arch = "wasm32"));
+                let load = ui.add_enabled(
+                    can_open_files && pitch_import.task.is_none() && !pitch_import.path.is_empty(),
+                    egui::Button::new("Load and simulate"),
+                );
+                if !can_open_files {
+                    ui.label("loading files needs the desktop build");
+                }
+                if load.clicked() {
+                    let path = pitch_import.path.clone();
+                    let assumed_spin_efficiency = pitch_import.assumed_spin_efficiency;
+                    let environment = *aero_environment;
+                    let profile = *aero_profile;
+                    let effects = *aero_effects;
+                    pitch_import.task = Some(AsyncComputeTaskPool::get().spawn(async move {
+                        load_pitch_csv(
+                            &path,
+                            assumed_spin_efficiency,
+                            &environment,
+                            &profile,
+                            &effects,
+                        )
+                    }));
+                }
+                if pitch_import.task.is_some() {
+                    ui.spinner();
+                }
+            });
+
+            let Some(result) = &pitch_import.result else {
+                return;
+            };
+            let pitches = match result {
+                Ok(pitches) => pitches,
+                Err(e) => {
+                    ui.colored_label(egui::Color32::RED, e.to_string());
+                    return;
+                }
+            };
+
+            ui.add_space(10.0);
+            let errors: Vec<Vec2> = pitches.iter().filter_map(|p| p.movement_error()).collect();
+            if !errors.is_empty() {
+                let mean_abs = errors.iter().map(|e| e.abs()).sum::<Vec2>() / errors.len() as f32;
+                ui.label(format!(
+                    "{} pitches, mean movement error {} horizontal, {} vertical",
+                    pitches.len(),
+                    units.length.format(mean_abs.x, 1),
+                    units.length.format(mean_abs.y, 1),
+                ));
+            } else {
+                ui.label(format!("{} pitches", pitches.len()));
+            }
+            if ui.button("Save arsenal as presets").clicked() {
+                for preset in arsenal_presets(pitches) {
+                    pitch_presets.save(preset);
+                }
+            }
+
+            ui.add_space(10.0);
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                egui::Grid::new("imported pitches")
+                    .striped(true)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.strong("type");
+                        ui.strong(units.speed.symbol());
+                        ui.strong(units.spin.symbol());
+                        ui.strong("tilt");
+                        for header in ["IVB rec", "IVB sim", "HB rec", "HB sim", "plate miss"] {
+                            ui.strong(unit_label(header, units.length));
+                        }
+                        ui.end_row();
+                        for pitch in pitches {
+                            let params = &pitch.recorded.params;
+                            let (hr, min) = params.tilt.to_hour_minutes();
+                            let recorded = pitch.recorded.movement;
+                            ui.label(&pitch.recorded.pitch_type);
+                            ui.label(format!(
+                                "{:.1}",
+                                convert(params.speed, SpeedUnit::Mph, units.speed)
+                            ));
+                            ui.label(format!(
+                                "{:.0}",
+                                convert(params.spin_rate, SpinUnit::Rpm, units.spin)
+                            ));
+                            ui.label(format!("{}:{:02}", hr, min));
+                            ui.label(format_length(
+                                recorded.map(|m| m.induced_vertical_break),
+                                units.length,
+                            ));
+                            ui.label(format_length(
+                                pitch.movement.map(|m| m.induced_vertical_break),
+                                units.length,
+                            ));
+                            ui.label(format_length(
+                                recorded.map(|m| m.horizontal_break),
+                                units.length,
+                            ));
+                            ui.label(format_length(
+                                pitch.movement.map(|m| m.horizontal_break),
+                                units.length,
+                            ));
+                            ui.label(format_length(pitch.plate_location_error(), units.length));
+                            ui.end_row();
+                        }
+                    });
+            });
+        });
+    pitch_import.open = open;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATCAST_CSV: &str = "\
+pitch_type,player_name,p_throws,release_speed,release_spin_rate,spin_axis,release_pos_x,release_pos_z,release_extension,pfx_x,pfx_z,plate_x,plate_z
+FF,\"Pitcher, A\",R,95.0,2400,210,-1.5,6.0,6.5,-0.5,1.4,0.2,2.5
+SL,\"Pitcher, A\",R,85.0,,90,-1.5,5.8,6.2,0.3,0.1,-0.4,2.0
+CH,\"Pitcher, B\",L,85.0,1800,240,1.5,5.8,6.2,0.3,0.1,-0.4,2.0
+";
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn reads_statcast_rows() {
+        let pitches = read_pitch_csv(STATCAST_CSV.as_bytes(), 0.9).unwrap();
+        // the slider has no spin rate and is skipped
+        assert_eq!(pitches.len(), 2);
+
+        let fastball = &pitches[0];
+        assert_eq!(fastball.pitcher, "Pitcher, A");
+        assert_eq!(fastball.pitch_type, "FF");
+        assert!(matches!(fastball.params.pitching_arm, PitchingArm::Righty));
+        assert_close(fastball.params.speed, 95.);
+        assert_close(fastball.params.spin_rate, 2400.);
+        assert_close(fastball.params.spin_efficiency, 0.9);
+        // 210° is half an hour past backspin
+        assert_close(tilt_to_clock_minutes(fastball.params.tilt), 60.);
+        assert_close(fastball.release_point.x, 1.5 / M_TO_FEET);
+        assert_close(fastball.release_point.y, 6. / M_TO_FEET);
+        assert_close(
+            fastball.release_point.z,
+            STATCAST_PITCHING_DISTANCE - 6.5 / M_TO_FEET,
+        );
+        assert!(fastball.release_velocity.is_none());
+        let movement = fastball.movement.unwrap();
+        assert_close(movement.horizontal_break, 0.5 / M_TO_FEET);
+        assert_close(movement.induced_vertical_break, 1.4 / M_TO_FEET);
+        let plate_location = fastball.plate_location.unwrap();
+        assert_close(plate_location.x, -0.2 / M_TO_FEET);
+        assert_close(plate_location.y, 2.5 / M_TO_FEET);
+        assert_close(plate_location.z, DEFAULT_FRONT_PANEL_POS_Z);
+
+        let changeup = &pitches[1];
+        assert!(matches!(changeup.params.pitching_arm, PitchingArm::Lefty));
+        assert_close(changeup.release_point.x, -1.5 / M_TO_FEET);
+        // toward first base is arm side for a lefty
+        assert_close(changeup.movement.unwrap().horizontal_break, 0.3 / M_TO_FEET);
+    }
+
+    #[test]
+    fn reads_trackman_rows() {
+        let csv = "\
+Pitcher,PitcherThrows,TaggedPitchType,RelSpeed,SpinRate,SpinAxis,HorzBreak,InducedVertBreak
+\"Pitcher, C\",Right,Fastball,92.5,2250,180,-8.0,16.0
+";
+        let pitches = read_pitch_csv(csv.as_bytes(), 0.9).unwrap();
+        assert_eq!(pitches.len(), 1);
+        let pitch = &pitches[0];
+        assert_eq!(pitch.pitch_type, "Fastball");
+        assert_close(tilt_to_clock_minutes(pitch.params.tilt), 0.);
+        let movement = pitch.movement.unwrap();
+        assert_close(movement.horizontal_break, 8. / M_TO_INCHES);
+        assert_close(movement.induced_vertical_break, 16. / M_TO_INCHES);
+        assert!(pitch.plate_location.is_none());
+    }
+
+    #[test]
+    fn takes_spin_efficiency_in_percent_or_fraction() {
+        let csv = "\
+release_speed,release_spin_rate,spin_axis,spin_efficiency
+90,2000,200,85
+90,2000,200,0.5
+";
+        let pitches = read_pitch_csv(csv.as_bytes(), 0.9).unwrap();
+        assert_close(pitches[0].params.spin_efficiency, 0.85);
+        assert_close(pitches[1].params.spin_efficiency, 0.5);
+    }
+
+    #[test]
+    fn carries_statcast_velocity_back_to_release() {
+        let csv = "\
+release_speed,release_spin_rate,spin_axis,release_pos_y,vx0,vy0,vz0,ax,ay,az
+90,2200,210,54.0,2.0,-130.0,-5.0,-10.0,28.0,-16.0
+90,2200,210,54.0,2.0,-130.0,-5.0,,,
+";
+        let pitches = read_pitch_csv(csv.as_bytes(), 0.9).unwrap();
+
+        // y(t) = 50 - 130 t + 14 t^2 = 54 at t = -0.03067 s
+        let t = (130. - (130f32.powi(2) + 4. * 14. * 4.).sqrt()) / 28.;
+        let expected = Vec3::new(2. - 10. * t, -130. + 28. * t, -5. - 16. * t) / M_TO_FEET;
+        let velocity = pitches[0].release_velocity.unwrap();
+        assert_close(velocity.x, -expected.x);
+        assert_close(velocity.y, expected.z);
+        assert_close(velocity.z, expected.y);
+
+        // without accelerations only gravity is taken back, over 4 ft at 130 ft/s
+        let t = -4. / 130.;
+        let velocity = pitches[1].release_velocity.unwrap();
+        assert_close(velocity.x, -2. / M_TO_FEET);
+        assert_close(velocity.y, -5. / M_TO_FEET + GRAVITY.y * t);
+        assert_close(velocity.z, -130. / M_TO_FEET);
+    }
+
+    #[test]
+    fn rejects_missing_required_columns() {
+        let csv = "release_speed,release_spin_rate\n90,2000\n";
+        assert!(read_pitch_csv(csv.as_bytes(), 0.9).is_err());
+    }
+}
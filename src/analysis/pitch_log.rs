@@ -0,0 +1,363 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+/// A pitch thrown this session.
+#[derive(Debug, Clone)]
+pub(crate) struct LoggedPitch {
+    pub pitch: StuffPitch,
+    pub measurement: PitchMeasurement,
+    /// the ball it was thrown with
+    pub baseball: Entity,
+    /// angular velocity of the ball in rad/s as it crossed the front of home plate,
+    /// once it has
+    pub plate_spin: Option<Vec3>,
+    /// set on fields that require an arc
+    pub arc: Option<DeliveryArc>,
+}
+
+/// Pitches thrown this session, in order.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct PitchLog {
+    pub pitches: Vec<LoggedPitch>,
+}
+
+impl PitchLog {
+    /// Stuff score of every pitch, against the session's primary fastball.
+    pub(crate) fn stuff_scores(&self, stuff_model: &StuffModel) -> Vec<f32> {
+        let fastball = primary_fastball(self.pitches.iter().map(|logged| &logged.pitch));
+        self.pitches
+            .iter()
+            .map(|logged| stuff_model.score(&logged.pitch, fastball.as_ref()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ReportSource {
+    #[default]
+    Session,
+    Presets,
+}
+
+/// Pitch report grouping the session's pitches, or the presets, by pitch class.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct PitchReport {
+    pub open: bool,
+    pub source: ReportSource,
+    pub edit_classifier: bool,
+    /// classified presets, refreshed whenever the presets change
+    pub presets: Vec<(String, StuffPitch)>,
+}
+
+pub(crate) fn log_thrown_pitches(
+    mut pitch_log: ResMut<PitchLog>,
+    thrown_pitch: Res<ThrownPitch>,
+    pitch_classifier: Res<PitchClassifier>,
+    stuff_model: Res<StuffModel>,
+    field: Res<FieldGeometry>,
+    environment: Res<AeroEnvironment>,
+    profile: Res<AeroProfile>,
+    effects: Res<AeroEffects>,
+    mut ev_activate_aerodynamics: EventReader<ActivateAerodynamicsEvent>,
+) {
+    for ev in ev_activate_aerodynamics.read() {
+        let params = thrown_pitch.0;
+        let Some(measurement) = measure_pitch(
+            &params,
+            params.direction,
+            &field,
+            &environment,
+            &profile,
+            &effects,
+        ) else {
+            continue;
+        };
+        let class = pitch_classifier.classify(&params, &measurement.movement);
+        let arc = field.delivery_arc(&simulate_pitch(
+            &params,
+            params.direction,
+            &field,
+            &environment,
+            &profile,
+            &effects,
+        ));
+        pitch_log.pitches.push(LoggedPitch {
+            pitch: StuffPitch::new(params, class, measurement.movement, &field),
+            measurement,
+            baseball: ev.entity,
+            plate_spin: None,
+            arc,
+        });
+        let stuff = pitch_log.stuff_scores(&stuff_model).last().copied();
+        info!(
+            "pitch {}: {}, stuff {:.0}",
+            pitch_log.pitches.len(),
+            class.label(),
+            stuff.unwrap_or_default()
+        );
+    }
+}
+
+/// Records the spin of the last thrown ball as it crosses the front of home plate,
+/// after whatever decay and precession it went through in flight.
+pub(crate) fn record_plate_spin(
+    mut pitch_log: ResMut<PitchLog>,
+    query_baseball: Query<(&Transform, &Velocity)>,
+) {
+    let Some(logged) = pitch_log.pitches.last() else {
+        return;
+    };
+    if logged.plate_spin.is_some() {
+        return;
+    }
+    let Ok((transform, velocity)) = query_baseball.get(logged.baseball) else {
+        return;
+    };
+    if transform.translation.z > DEFAULT_FRONT_PANEL_POS_Z {
+        return;
+    }
+    let spin = velocity.angvel;
+    if let Some(logged) = pitch_log.pitches.last_mut() {
+        logged.plate_spin = Some(spin);
+    }
+}
+
+pub(crate) fn classify_presets(
+    mut pitch_report: ResMut<PitchReport>,
+    pitch_presets: Res<PitchPresets>,
+    pitch_classifier: Res<PitchClassifier>,
+    field: Res<FieldGeometry>,
+    environment: Res<AeroEnvironment>,
+    profile: Res<AeroProfile>,
+    effects: Res<AeroEffects>,
+) {
+    let outdated = pitch_presets.is_changed()
+        || pitch_classifier.is_changed()
+        || field.is_changed()
+        || environment.is_changed()
+        || profile.is_changed()
+        || effects.is_changed();
+    if !outdated {
+        return;
+    }
+    pitch_report.presets = pitch_presets
+        .0
+        .iter()
+        .filter_map(|preset| {
+            let movement =
+                measure_movement(&preset.params, &field, &environment, &profile, &effects)?;
+            let class = pitch_classifier.classify(&preset.params, &movement);
+            Some((
+                preset.name.clone(),
+                StuffPitch::new(preset.params, class, movement, &field),
+            ))
+        })
+        .collect();
+}
+
+/// Average speed, spin, movement and stuff of each class.
+fn report_grid<'a>(
+    ui: &mut egui::Ui,
+    pitches: impl Iterator<Item = (&'a StuffPitch, f32)>,
+    units: &Units,
+) {
+    let mut groups: BTreeMap<PitchClass, Vec<(&StuffPitch, f32)>> = BTreeMap::new();
+    for (pitch, stuff) in pitches {
+        groups.entry(pitch.class).or_default().push((pitch, stuff));
+    }
+    egui::Grid::new("pitch report")
+        .striped(true)
+        .spacing([16.0, 4.0])
+        .show(ui, |ui| {
+            ui.strong("class");
+            ui.strong("count");
+            ui.strong(units.speed.symbol());
+            ui.strong(units.spin.symbol());
+            ui.strong(unit_label("IVB", units.length));
+            ui.strong(unit_label("HB", units.length));
+            ui.strong("stuff");
+            ui.end_row();
+            for (class, pitches) in groups {
+                let n = pitches.len() as f32;
+                let mean = |f: &dyn Fn(&StuffPitch, f32) -> f32| {
+                    pitches.iter().map(|(p, stuff)| f(p, *stuff)).sum::<f32>() / n
+                };
+                ui.label(class.label());
+                ui.label(pitches.len().to_string());
+                ui.label(format!(
+                    "{:.1}",
+                    convert(mean(&|p, _| p.params.speed), SpeedUnit::Mph, units.speed)
+                ));
+                ui.label(format!(
+                    "{:.0}",
+                    convert(mean(&|p, _| p.params.spin_rate), SpinUnit::Rpm, units.spin)
+                ));
+                ui.label(format!(
+                    "{:.1}",
+                    units
+                        .length
+                        .from_base(mean(&|p, _| p.movement.induced_vertical_break))
+                ));
+                ui.label(format!(
+                    "{:.1}",
+                    units
+                        .length
+                        .from_base(mean(&|p, _| p.movement.horizontal_break))
+                ));
+                ui.label(format!("{:.0}", mean(&|_, stuff| stuff)));
+                ui.end_row();
+            }
+        });
+}
+
+pub(crate) fn pitch_report_window(
+    mut contexts: EguiContexts,
+    mut pitch_report: ResMut<PitchReport>,
+    mut pitch_log: ResMut<PitchLog>,
+    mut pitch_classifier: ResMut<PitchClassifier>,
+    mut stuff_model: ResMut<StuffModel>,
+    units: Res<Units>,
+) {
+    let ctx = contexts.ctx_mut();
+    let pitch_report = pitch_report.as_mut();
+
+    let mut open = pitch_report.open;
+    egui::Window::new("pitch report")
+        .open(&mut open)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut pitch_report.source, ReportSource::Session, "Session");
+                ui.selectable_value(&mut pitch_report.source, ReportSource::Presets, "Presets");
+            });
+            ui.add_space(10.0);
+
+            match pitch_report.source {
+                ReportSource::Session => {
+                    let stuff_scores = pitch_log.stuff_scores(&stuff_model);
+                    report_grid(
+                        ui,
+                        pitch_log
+                            .pitches
+                            .iter()
+                            .map(|logged| &logged.pitch)
+                            .zip(stuff_scores.iter().copied()),
+                        &units,
+                    );
+                    ui.add_space(10.0);
+                    egui::CollapsingHeader::new("pitches").show(ui, |ui| {
+                        egui::Grid::new("logged pitches")
+                            .striped(true)
+                            .spacing([16.0, 4.0])
+                            .show(ui, |ui| {
+                                for (i, (logged, stuff)) in
+                                    pitch_log.pitches.iter().zip(&stuff_scores).enumerate()
+                                {
+                                    let pitch = &logged.pitch;
+                                    ui.label(format!("{}", i + 1));
+                                    ui.label(pitch.class.label());
+                                    ui.label(
+                                        units.speed.format(pitch.params.speed * MPH_TO_MPS, 1),
+                                    );
+                                    ui.label(format!(
+                                        "{:.1} / {}",
+                                        units
+                                            .length
+                                            .from_base(pitch.movement.induced_vertical_break),
+                                        units.length.format(pitch.movement.horizontal_break, 1)
+                                    ));
+                                    ui.label(format!("stuff {:.0}", stuff));
+                                    let release_spin = logged.measurement.release_spin;
+                                    ui.label(match logged.plate_spin {
+                                        Some(plate_spin) => format!(
+                                            "spin {:.0} → {}, axis {:.1}°",
+                                            units.spin.from_base(release_spin.length()),
+                                            units.spin.format(plate_spin.length(), 0),
+                                            release_spin.angle_between(plate_spin).to_degrees()
+                                        ),
+                                        None => format!(
+                                            "spin {} → -",
+                                            units.spin.format(release_spin.length(), 0)
+                                        ),
+                                    });
+                                    match logged.arc {
+                                        Some(arc) if arc.legal => {
+                                            ui.label(format!(
+                                                "legal arc, peaked at {}",
+                                                units.distance.format(arc.peak, 1)
+                                            ));
+                                        }
+                                        Some(arc) => {
+                                            ui.colored_label(
+                                                egui::Color32::RED,
+                                                format!(
+                                                    "illegal arc, peaked at {}",
+                                                    units.distance.format(arc.peak, 1)
+                                                ),
+                                            );
+                                        }
+                                        None => {
+                                            ui.label("");
+                                        }
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                    if ui.button("Clear session").clicked() {
+                        pitch_log.pitches.clear();
+                    }
+                }
+                ReportSource::Presets => {
+                    let fastball =
+                        primary_fastball(pitch_report.presets.iter().map(|(_, pitch)| pitch));
+                    let stuff_scores: Vec<f32> = pitch_report
+                        .presets
+                        .iter()
+                        .map(|(_, pitch)| stuff_model.score(pitch, fastball.as_ref()))
+                        .collect();
+                    report_grid(
+                        ui,
+                        pitch_report
+                            .presets
+                            .iter()
+                            .map(|(_, pitch)| pitch)
+                            .zip(stuff_scores.iter().copied()),
+                        &units,
+                    );
+                    ui.add_space(10.0);
+                    egui::Grid::new("classified presets")
+                        .spacing([16.0, 4.0])
+                        .show(ui, |ui| {
+                            for ((name, pitch), stuff) in
+                                pitch_report.presets.iter().zip(&stuff_scores)
+                            {
+                                ui.label(name);
+                                ui.label(pitch.class.label());
+                                ui.label(format!("stuff {:.0}", stuff));
+                                ui.end_row();
+                            }
+                        });
+                }
+            }
+
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut pitch_report.edit_classifier, "edit classifier");
+                // the web build has no file system to reload the model from
+                #[cfg(not(target_arch = "wasm32"))]
+                if ui.button("Reload stuff model").clicked() {
+                    match StuffModel::load() {
+                        Ok(model) => *stuff_model = model,
+                        Err(e) => warn!("couldn't reload the stuff model: {}", e),
+                    }
+                }
+            });
+            if pitch_report.edit_classifier {
+                // only mark the classifier changed when a value was actually edited
+                let mut classifier = pitch_classifier.clone();
+                classifier.edit(ui, &units);
+                pitch_classifier.set_if_neq(classifier);
+            }
+        });
+    pitch_report.open = open;
+}
@@ -0,0 +1,265 @@
+use crate::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+use std::f32::consts::PI;
+
+/// number of cells along each seam angle
+const HEATMAP_RESOLUTION: usize = 24;
+const HEATMAP_CELL_SIZE: f32 = 14.0;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SeamCell {
+    pub seam_y_angle: f32,
+    pub seam_z_angle: f32,
+    /// movement from the seam-shifted wake alone
+    pub movement: PitchMovement,
+}
+
+impl SeamCell {
+    pub(crate) fn magnitude(&self) -> f32 {
+        Vec2::new(
+            self.movement.horizontal_break,
+            self.movement.induced_vertical_break,
+        )
+        .length()
+    }
+}
+
+/// Non-Magnus movement over every seam orientation of one pitch, row major over the z angle.
+#[derive(Debug, Clone)]
+pub(crate) struct SeamHeatmap {
+    pub pitch: PitchParams,
+    pub cells: Vec<SeamCell>,
+}
+
+impl SeamHeatmap {
+    fn extreme(&self, ordering: std::cmp::Ordering) -> Option<&SeamCell> {
+        self.cells.iter().reduce(|a, b| {
+            if b.magnitude().total_cmp(&a.magnitude()) == ordering {
+                b
+            } else {
+                a
+            }
+        })
+    }
+
+    pub(crate) fn most(&self) -> Option<&SeamCell> {
+        self.extreme(std::cmp::Ordering::Greater)
+    }
+
+    pub(crate) fn least(&self) -> Option<&SeamCell> {
+        self.extreme(std::cmp::Ordering::Less)
+    }
+
+    /// Whether the heatmap was computed for `pitch`, ignoring its seam orientation and aim.
+    pub(crate) fn matches(&self, pitch: &PitchParams) -> bool {
+        let a = &self.pitch;
+        a.pitching_arm == pitch.pitching_arm
+            && a.speed == pitch.speed
+            && a.spin_rate == pitch.spin_rate
+            && a.spin_efficiency == pitch.spin_efficiency
+            && tilt_to_clock_minutes(a.tilt) == tilt_to_clock_minutes(pitch.tilt)
+            && a.gyro_pole == pitch.gyro_pole
+    }
+}
+
+/// Sweeps the seam orientation of a pitch to find where the seam-shifted wake moves it most.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct SeamOptimizer {
+    pub open: bool,
+    pub heatmap: Option<SeamHeatmap>,
+    task: Option<Task<SeamHeatmap>>,
+}
+
+fn cell_angle(i: usize) -> f32 {
+    -PI + (i as f32 + 0.5) * 2. * PI / HEATMAP_RESOLUTION as f32
+}
+
+pub(crate) fn sweep_seam_orientations(
+    pitch: PitchParams,
+    field: FieldGeometry,
+    environment: AeroEnvironment,
+    profile: AeroProfile,
+    effects: AeroEffects,
+) -> SeamHeatmap {
+    let direction = analysis_direction(&pitch, &field);
+    // seam orientation only changes the seam-shifted wake, so the rest is simulated once
+    let effects = AeroEffects {
+        ssw_on: true,
+        ..effects
+    };
+    let without_ssw = AeroEffects {
+        ssw_on: false,
+        ..effects
+    };
+    let reference = simulate_pitch(
+        &pitch,
+        direction,
+        &field,
+        &environment,
+        &profile,
+        &without_ssw,
+    )
+    .plate_location();
+
+    let mut cells = Vec::with_capacity(HEATMAP_RESOLUTION * HEATMAP_RESOLUTION);
+    for z in 0..HEATMAP_RESOLUTION {
+        for y in 0..HEATMAP_RESOLUTION {
+            let pitch = PitchParams {
+                seam_y_angle: cell_angle(y),
+                seam_z_angle: cell_angle(z),
+                ..pitch
+            };
+            let location =
+                simulate_pitch(&pitch, direction, &field, &environment, &profile, &effects)
+                    .plate_location();
+            let movement = match (location, reference) {
+                (Some(location), Some(reference)) => {
+                    PitchMovement::between(location, reference, pitch.pitching_arm)
+                }
+                _ => PitchMovement::default(),
+            };
+            cells.push(SeamCell {
+                seam_y_angle: pitch.seam_y_angle,
+                seam_z_angle: pitch.seam_z_angle,
+                movement,
+            });
+        }
+    }
+    SeamHeatmap { pitch, cells }
+}
+
+pub(crate) fn poll_seam_optimizer(mut seam_optimizer: ResMut<SeamOptimizer>) {
+    let Some(task) = seam_optimizer.task.as_mut() else {
+        return;
+    };
+    if let Some(heatmap) = block_on(future::poll_once(task)) {
+        seam_optimizer.task = None;
+        seam_optimizer.heatmap = Some(heatmap);
+    }
+}
+
+fn heatmap_color(t: f32) -> egui::Color32 {
+    let t = t.clamp(0., 1.);
+    egui::Color32::from_rgb(
+        (40. + 215. * t) as u8,
+        (40. + 170. * t) as u8,
+        (120. * (1. - t)) as u8,
+    )
+}
+
+fn cell_label(cell: &SeamCell, unit: LengthUnit) -> String {
+    format!(
+        "y {:.0}°, z {:.0}°: {} ({:.1} IVB, {:.1} HB)",
+        cell.seam_y_angle.to_degrees(),
+        cell.seam_z_angle.to_degrees(),
+        unit.format(cell.magnitude(), 1),
+        unit.from_base(cell.movement.induced_vertical_break),
+        unit.from_base(cell.movement.horizontal_break),
+    )
+}
+
+pub(crate) fn seam_optimizer_window(
+    mut contexts: EguiContexts,
+    mut seam_optimizer: ResMut<SeamOptimizer>,
+    mut selected_pitch_parameters: ResMut<SelectedPitchParameters>,
+    field_geometry: Res<FieldGeometry>,
+    aero_environment: Res<AeroEnvironment>,
+    aero_profile: Res<AeroProfile>,
+    aero_effects: Res<AeroEffects>,
+    units: Res<Units>,
+) {
+    let ctx = contexts.ctx_mut();
+    let seam_optimizer = seam_optimizer.as_mut();
+
+    let mut open = seam_optimizer.open;
+    egui::Window::new("seam orientation")
+        .open(&mut open)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let sweep = ui.add_enabled(
+                    seam_optimizer.task.is_none(),
+                    egui::Button::new("Sweep current pitch"),
+                );
+                if sweep.clicked() {
+                    let pitch = selected_pitch_parameters.0;
+                    let field = *field_geometry;
+                    let environment = *aero_environment;
+                    let profile = *aero_profile;
+                    let effects = *aero_effects;
+                    seam_optimizer.task = Some(AsyncComputeTaskPool::get().spawn(async move {
+                        sweep_seam_orientations(pitch, field, environment, profile, effects)
+                    }));
+                }
+                if seam_optimizer.task.is_some() {
+                    ui.spinner();
+                }
+            });
+
+            let Some(heatmap) = &seam_optimizer.heatmap else {
+                return;
+            };
+            if !heatmap.matches(&selected_pitch_parameters.0) {
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    "pitch changed since the sweep, sweep again to update",
+                );
+            }
+            let (Some(most), Some(least)) = (heatmap.most(), heatmap.least()) else {
+                return;
+            };
+            let max = most.magnitude().max(f32::EPSILON);
+            ui.label(format!("most: {}", cell_label(most, units.length)));
+            ui.label(format!("least: {}", cell_label(least, units.length)));
+            ui.add_space(10.0);
+
+            ui.horizontal(|ui| {
+                ui.label("z angle ↓ / y angle →");
+            });
+            let size = egui::Vec2::splat(HEATMAP_CELL_SIZE * HEATMAP_RESOLUTION as f32);
+            let (response, painter) = ui.allocate_painter(size, egui::Sense::click());
+            let origin = response.rect.min;
+            let cell_rect = |y: usize, z: usize| {
+                egui::Rect::from_min_size(
+                    origin + egui::vec2(y as f32, z as f32) * HEATMAP_CELL_SIZE,
+                    egui::Vec2::splat(HEATMAP_CELL_SIZE),
+                )
+            };
+            for (i, cell) in heatmap.cells.iter().enumerate() {
+                let rect = cell_rect(i % HEATMAP_RESOLUTION, i / HEATMAP_RESOLUTION);
+                painter.rect_filled(rect, 0.0, heatmap_color(cell.magnitude() / max));
+            }
+
+            // outline the cell holding the current orientation
+            let index_of = |angle: f32| {
+                (((angle + PI) / (2. * PI) * HEATMAP_RESOLUTION as f32) as usize)
+                    .min(HEATMAP_RESOLUTION - 1)
+            };
+            let current = &selected_pitch_parameters.0;
+            painter.rect_stroke(
+                cell_rect(
+                    index_of(current.seam_y_angle),
+                    index_of(current.seam_z_angle),
+                ),
+                0.0,
+                egui::Stroke::new(2.0, egui::Color32::WHITE),
+            );
+
+            let hovered = response.hover_pos().map(|pos| {
+                let offset = (pos - origin) / HEATMAP_CELL_SIZE;
+                let y = (offset.x as usize).min(HEATMAP_RESOLUTION - 1);
+                let z = (offset.y as usize).min(HEATMAP_RESOLUTION - 1);
+                heatmap.cells[z * HEATMAP_RESOLUTION + y]
+            });
+            if let Some(cell) = hovered {
+                if response.clicked() {
+                    selected_pitch_parameters.0.seam_y_angle = cell.seam_y_angle;
+                    selected_pitch_parameters.0.seam_z_angle = cell.seam_z_angle;
+                }
+                response.on_hover_text(format!(
+                    "{}\nclick to apply",
+                    cell_label(&cell, units.length)
+                ));
+            }
+        });
+    seam_optimizer.open = open;
+}
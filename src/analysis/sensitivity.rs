@@ -0,0 +1,403 @@
+use crate::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+
+/// A `PitchParams` field a sensitivity sweep can vary. Speed and spin are swept in mph and
+/// rpm, and shown in the chosen `Units`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum SweepParameter {
+    Speed,
+    SpinRate,
+    #[default]
+    Tilt,
+    SpinEfficiency,
+    SeamYAngle,
+    SeamZAngle,
+}
+
+impl SweepParameter {
+    pub(crate) const ALL: [SweepParameter; 6] = [
+        SweepParameter::Speed,
+        SweepParameter::SpinRate,
+        SweepParameter::Tilt,
+        SweepParameter::SpinEfficiency,
+        SweepParameter::SeamYAngle,
+        SweepParameter::SeamZAngle,
+    ];
+
+    pub(crate) fn label(&self, units: &Units) -> String {
+        match self {
+            SweepParameter::Speed => unit_label("speed", units.speed),
+            SweepParameter::SpinRate => unit_label("spin rate", units.spin),
+            SweepParameter::Tilt => "tilt (clock minutes)".to_string(),
+            SweepParameter::SpinEfficiency => "spin efficiency (%)".to_string(),
+            SweepParameter::SeamYAngle => "seam y angle (°)".to_string(),
+            SweepParameter::SeamZAngle => "seam z angle (°)".to_string(),
+        }
+    }
+
+    /// A swept `value` as `units` shows it.
+    pub(crate) fn shown(&self, value: f32, units: &Units) -> f32 {
+        match self {
+            SweepParameter::Speed => convert(value, SpeedUnit::Mph, units.speed),
+            SweepParameter::SpinRate => convert(value, SpinUnit::Rpm, units.spin),
+            _ => value,
+        }
+    }
+
+    /// Inverse of `shown`.
+    pub(crate) fn swept(&self, value: f32, units: &Units) -> f32 {
+        match self {
+            SweepParameter::Speed => convert(value, units.speed, SpeedUnit::Mph),
+            SweepParameter::SpinRate => convert(value, units.spin, SpinUnit::Rpm),
+            _ => value,
+        }
+    }
+
+    /// Widest range the parameter can be swept over. Tilt and seam angles wrap around, so their
+    /// range reaches half a turn past either end and a sweep can cross 12:00 or ±180°.
+    pub(crate) fn bounds(&self) -> (f32, f32) {
+        match self {
+            SweepParameter::Speed => (40., 105.),
+            SweepParameter::SpinRate => (0., 3500.),
+            SweepParameter::Tilt => (-360., 1080.),
+            SweepParameter::SpinEfficiency => (0., 100.),
+            SweepParameter::SeamYAngle | SweepParameter::SeamZAngle => (-360., 360.),
+        }
+    }
+
+    fn default_half_range(&self) -> f32 {
+        match self {
+            SweepParameter::Speed => 3.,
+            SweepParameter::SpinRate => 200.,
+            SweepParameter::Tilt => 15.,
+            SweepParameter::SpinEfficiency => 10.,
+            SweepParameter::SeamYAngle | SweepParameter::SeamZAngle => 30.,
+        }
+    }
+
+    /// Range swept around the parameter's value in `pitch` when the parameter is picked.
+    pub(crate) fn default_range(&self, pitch: &PitchParams) -> (f32, f32) {
+        let value = self.get(pitch);
+        let (lower, upper) = self.bounds();
+        let half_range = self.default_half_range();
+        (
+            (value - half_range).max(lower),
+            (value + half_range).min(upper),
+        )
+    }
+
+    pub(crate) fn get(&self, pitch: &PitchParams) -> f32 {
+        match self {
+            SweepParameter::Speed => pitch.speed,
+            SweepParameter::SpinRate => pitch.spin_rate,
+            SweepParameter::Tilt => tilt_to_clock_minutes(pitch.tilt),
+            SweepParameter::SpinEfficiency => pitch.spin_efficiency * 100.,
+            SweepParameter::SeamYAngle => pitch.seam_y_angle.to_degrees(),
+            SweepParameter::SeamZAngle => pitch.seam_z_angle.to_degrees(),
+        }
+    }
+
+    pub(crate) fn with(&self, pitch: &PitchParams, value: f32) -> PitchParams {
+        let mut pitch = *pitch;
+        match self {
+            SweepParameter::Speed => pitch.speed = value,
+            SweepParameter::SpinRate => pitch.spin_rate = value,
+            SweepParameter::Tilt => pitch.tilt = tilt_from_clock_minutes(value),
+            SweepParameter::SpinEfficiency => pitch.spin_efficiency = value / 100.,
+            SweepParameter::SeamYAngle => pitch.seam_y_angle = value.to_radians(),
+            SweepParameter::SeamZAngle => pitch.seam_z_angle = value.to_radians(),
+        }
+        pitch
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SweepSample {
+    pub value: f32,
+    pub trajectory: Trajectory,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SweepResult {
+    pub parameter: SweepParameter,
+    pub pitching_arm: PitchingArm,
+    /// plate location of the unchanged pitch
+    pub reference: Option<Vec3>,
+    pub samples: Vec<SweepSample>,
+}
+
+/// Varies one pitch parameter over a range and compares where each pitch ends up.
+#[derive(Debug, Resource)]
+pub(crate) struct SensitivitySweep {
+    pub open: bool,
+    pub parameter: SweepParameter,
+    pub min: f32,
+    pub max: f32,
+    pub count: usize,
+    pub result: Option<SweepResult>,
+    task: Option<Task<SweepResult>>,
+}
+
+impl Default for SensitivitySweep {
+    fn default() -> Self {
+        let parameter = SweepParameter::default();
+        let (min, max) = parameter.default_range(&SelectedPitchParameters::default().0);
+        Self {
+            open: false,
+            parameter,
+            min,
+            max,
+            count: 11,
+            result: None,
+            task: None,
+        }
+    }
+}
+
+pub(crate) fn sweep_parameter(
+    pitch: PitchParams,
+    parameter: SweepParameter,
+    min: f32,
+    max: f32,
+    count: usize,
+    field: FieldGeometry,
+    environment: AeroEnvironment,
+    profile: AeroProfile,
+    effects: AeroEffects,
+) -> SweepResult {
+    // every pitch is aimed the same way, so only the swept parameter moves them
+    let direction = analysis_direction(&pitch, &field);
+    let reference = simulate_pitch(&pitch, direction, &field, &environment, &profile, &effects)
+        .plate_location();
+    let samples = (0..count)
+        .map(|i| {
+            let value = min + (max - min) * i as f32 / (count - 1).max(1) as f32;
+            let trajectory = simulate_pitch(
+                &parameter.with(&pitch, value),
+                direction,
+                &field,
+                &environment,
+                &profile,
+                &effects,
+            );
+            SweepSample { value, trajectory }
+        })
+        .collect();
+    SweepResult {
+        parameter,
+        pitching_arm: pitch.pitching_arm,
+        reference,
+        samples,
+    }
+}
+
+pub(crate) fn poll_sensitivity_sweep(mut sensitivity_sweep: ResMut<SensitivitySweep>) {
+    let Some(task) = sensitivity_sweep.task.as_mut() else {
+        return;
+    };
+    if let Some(result) = block_on(future::poll_once(task)) {
+        sensitivity_sweep.task = None;
+        sensitivity_sweep.result = Some(result);
+    }
+}
+
+/// Color of the `i`th of `count` swept pitches, from blue at the low end to red at the high end.
+fn sample_color(i: usize, count: usize) -> Color {
+    let t = i as f32 / (count - 1).max(1) as f32;
+    Color::hsl(240. * (1. - t), 0.9, 0.55)
+}
+
+pub(crate) fn draw_sensitivity_fan(mut gizmos: Gizmos, sensitivity_sweep: Res<SensitivitySweep>) {
+    let Some(result) = &sensitivity_sweep.result else {
+        return;
+    };
+    let count = result.samples.len();
+    for (i, sample) in result.samples.iter().enumerate() {
+        let color = sample_color(i, count);
+        gizmos.linestrip(sample.trajectory.positions(), color);
+        if let Some(plate_location) = sample.trajectory.plate_location() {
+            gizmos.sphere(plate_location, Quat::IDENTITY, BASEBALL_RADIUS, color);
+        }
+    }
+}
+
+pub(crate) fn sensitivity_sweep_window(
+    mut contexts: EguiContexts,
+    mut sensitivity_sweep: ResMut<SensitivitySweep>,
+    selected_pitch_parameters: Res<SelectedPitchParameters>,
+    field_geometry: Res<FieldGeometry>,
+    aero_environment: Res<AeroEnvironment>,
+    aero_profile: Res<AeroProfile>,
+    aero_effects: Res<AeroEffects>,
+    units: Res<Units>,
+) {
+    let ctx = contexts.ctx_mut();
+    let sensitivity_sweep = sensitivity_sweep.as_mut();
+
+    let mut open = sensitivity_sweep.open;
+    egui::Window::new("sensitivity sweep")
+        .open(&mut open)
+        .default_size([420.0, 420.0])
+        .show(ctx, |ui| {
+            let previous_parameter = sensitivity_sweep.parameter;
+            egui::ComboBox::from_label("parameter")
+                .selected_text(sensitivity_sweep.parameter.label(&units))
+                .show_ui(ui, |ui| {
+                    for parameter in SweepParameter::ALL {
+                        ui.selectable_value(
+                            &mut sensitivity_sweep.parameter,
+                            parameter,
+                            parameter.label(&units),
+                        );
+                    }
+                });
+            let parameter = sensitivity_sweep.parameter;
+            let (lower, upper) = parameter.bounds();
+            if parameter != previous_parameter {
+                // center the range on the current pitch
+                (sensitivity_sweep.min, sensitivity_sweep.max) =
+                    parameter.default_range(&selected_pitch_parameters.0);
+            }
+
+            egui::Grid::new("sweep range")
+                .spacing([20.0, 10.0])
+                .show(ui, |ui| {
+                    let shown_range =
+                        parameter.shown(lower, &units)..=parameter.shown(upper, &units);
+                    for (label, value) in [
+                        ("from", &mut sensitivity_sweep.min),
+                        ("to", &mut sensitivity_sweep.max),
+                    ] {
+                        ui.label(label);
+                        let mut shown = parameter.shown(*value, &units);
+                        if egui::Slider::new(&mut shown, shown_range.clone())
+                            .ui(ui)
+                            .changed()
+                        {
+                            *value = parameter.swept(shown, &units);
+                        }
+                        ui.end_row();
+                    }
+                    ui.label("pitches");
+                    egui::Slider::new(&mut sensitivity_sweep.count, 2..=31).ui(ui);
+                    ui.end_row();
+                });
+
+            ui.horizontal(|ui| {
+                let sweep =
+                    ui.add_enabled(sensitivity_sweep.task.is_none(), egui::Button::new("Sweep"));
+                if sweep.clicked() {
+                    let pitch = selected_pitch_parameters.0;
+                    let (min, max, count) = (
+                        sensitivity_sweep.min,
+                        sensitivity_sweep.max,
+                        sensitivity_sweep.count,
+                    );
+                    let field = *field_geometry;
+                    let environment = *aero_environment;
+                    let profile = *aero_profile;
+                    let effects = *aero_effects;
+                    sensitivity_sweep.task = Some(AsyncComputeTaskPool::get().spawn(async move {
+                        sweep_parameter(
+                            pitch,
+                            parameter,
+                            min,
+                            max,
+                            count,
+                            field,
+                            environment,
+                            profile,
+                            effects,
+                        )
+                    }));
+                }
+                if sensitivity_sweep.task.is_some() {
+                    ui.spinner();
+                }
+                if ui.button("Clear").clicked() {
+                    sensitivity_sweep.result = None;
+                }
+            });
+
+            let Some(result) = &sensitivity_sweep.result else {
+                return;
+            };
+            let Some(reference) = result.reference else {
+                return;
+            };
+            let arm_side = match result.pitching_arm {
+                PitchingArm::Righty => 1.,
+                PitchingArm::Lefty => -1.,
+            };
+            // plate location relative to the unchanged pitch along `axis`
+            let series = |axis: Vec3| -> egui_plot::PlotPoints {
+                result
+                    .samples
+                    .iter()
+                    .filter_map(|sample| {
+                        let location = sample.trajectory.plate_location()?;
+                        Some([
+                            result.parameter.shown(sample.value, &units) as f64,
+                            units.length.from_base((location - reference).dot(axis)) as f64,
+                        ])
+                    })
+                    .collect()
+            };
+
+            egui_plot::Plot::new("sensitivity")
+                .legend(egui_plot::Legend::default())
+                .x_axis_label(result.parameter.label(&units))
+                .y_axis_label(unit_label("plate miss", units.length))
+                .show(ui, |plot_ui| {
+                    plot_ui.line(
+                        egui_plot::Line::new(series(Vec3::X * arm_side))
+                            .name("horizontal (+ arm side)"),
+                    );
+                    plot_ui.line(egui_plot::Line::new(series(Vec3::Y)).name("vertical"));
+                });
+        });
+    sensitivity_sweep.open = open;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pitch_at_tilt(minutes: f32) -> PitchParams {
+        PitchParams {
+            tilt: tilt_from_clock_minutes(minutes),
+            ..SelectedPitchParameters::default().0
+        }
+    }
+
+    #[test]
+    fn tilt_wraps_around_twelve() {
+        let pitch = SelectedPitchParameters::default().0;
+        let tilt = SweepParameter::Tilt;
+        // half an hour before 12:00 is 11:30, and half an hour past a full turn is 12:30
+        assert_eq!(tilt.get(&tilt.with(&pitch, -30.)), 690.);
+        assert_eq!(tilt.get(&tilt.with(&pitch, 750.)), 30.);
+        assert_eq!(tilt.get(&tilt.with(&pitch, 720.)), 0.);
+    }
+
+    #[test]
+    fn default_range_can_cross_twelve() {
+        let (min, max) = SweepParameter::Tilt.default_range(&pitch_at_tilt(5.));
+        assert_eq!((min, max), (-10., 20.));
+        let (min, max) = SweepParameter::Tilt.default_range(&pitch_at_tilt(710.));
+        assert_eq!((min, max), (695., 725.));
+    }
+
+    #[test]
+    fn speed_and_spin_are_shown_in_the_chosen_units() {
+        let units = Units::METRIC;
+        for (parameter, value) in [
+            (SweepParameter::Speed, 90.),
+            (SweepParameter::SpinRate, 2200.),
+        ] {
+            let shown = parameter.shown(value, &units);
+            assert!((parameter.swept(shown, &units) - value).abs() < 1e-3);
+        }
+        assert!((SweepParameter::Speed.shown(100., &units) - 160.93).abs() < 1e-2);
+    }
+}
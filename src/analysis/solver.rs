@@ -0,0 +1,95 @@
+/// Minimizes `cost` with the Nelder-Mead simplex method, starting from `start`
+/// with an initial simplex of size `step` along each dimension.
+/// Returns the best point found and its cost.
+pub(crate) fn nelder_mead(
+    cost: impl Fn(&[f32]) -> f32,
+    start: &[f32],
+    step: f32,
+    iterations: usize,
+) -> (Vec<f32>, f32) {
+    let n = start.len();
+    let mut simplex: Vec<(Vec<f32>, f32)> = (0..=n)
+        .map(|i| {
+            let mut point = start.to_vec();
+            if i > 0 {
+                point[i - 1] += step;
+            }
+            let value = cost(&point);
+            (point, value)
+        })
+        .collect();
+
+    for _ in 0..iterations {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let (worst, worst_cost) = simplex[n].clone();
+
+        let centroid: Vec<f32> = (0..n)
+            .map(|d| simplex[..n].iter().map(|(point, _)| point[d]).sum::<f32>() / n as f32)
+            .collect();
+        let towards = |t: f32| -> Vec<f32> {
+            centroid
+                .iter()
+                .zip(worst.iter())
+                .map(|(c, w)| c + t * (c - w))
+                .collect()
+        };
+
+        let reflected = towards(1.);
+        let reflected_cost = cost(&reflected);
+        if reflected_cost < simplex[0].1 {
+            let expanded = towards(2.);
+            let expanded_cost = cost(&expanded);
+            simplex[n] = if expanded_cost < reflected_cost {
+                (expanded, expanded_cost)
+            } else {
+                (reflected, reflected_cost)
+            };
+        } else if reflected_cost < simplex[n - 1].1 {
+            simplex[n] = (reflected, reflected_cost);
+        } else {
+            let contracted = towards(if reflected_cost < worst_cost {
+                0.5
+            } else {
+                -0.5
+            });
+            let contracted_cost = cost(&contracted);
+            if contracted_cost < worst_cost.min(reflected_cost) {
+                simplex[n] = (contracted, contracted_cost);
+            } else {
+                // shrink toward the best point
+                let best = simplex[0].0.clone();
+                for (point, value) in simplex.iter_mut().skip(1) {
+                    for (p, b) in point.iter_mut().zip(best.iter()) {
+                        *p = b + 0.5 * (*p - b);
+                    }
+                    *value = cost(point);
+                }
+            }
+        }
+    }
+
+    simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+    simplex.swap_remove(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_minimum_of_a_quadratic() {
+        let cost = |x: &[f32]| (x[0] - 1.).powi(2) + 2. * (x[1] + 2.).powi(2);
+        let (best, best_cost) = nelder_mead(cost, &[0., 0.], 0.5, 200);
+        assert!((best[0] - 1.).abs() < 1e-2, "{best:?}");
+        assert!((best[1] + 2.).abs() < 1e-2, "{best:?}");
+        assert!(best_cost < 1e-4);
+    }
+
+    #[test]
+    fn never_returns_worse_than_the_start() {
+        let cost = |x: &[f32]| x[0].abs() + (x[1] * 3.).sin();
+        let start = [0.3, -0.2];
+        let (_, best_cost) = nelder_mead(cost, &start, 0.1, 20);
+        assert!(best_cost <= cost(&start));
+    }
+}
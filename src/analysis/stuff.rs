@@ -0,0 +1,187 @@
+use crate::prelude::*;
+use bevy::asset::ron;
+use serde::Deserialize;
+
+/// path of the model in the asset folder; the built-in model is this file at build time
+const STUFF_MODEL_PATH: &str = "data/stuff_model.ron";
+/// z-scores are clamped to this many standard deviations so one feature can't dominate
+const MAX_Z_SCORE: f32 = 3.;
+
+/// A measurable trait of a pitch, see `assets/data/stuff_model.ron`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub(crate) enum StuffFeature {
+    Velocity,
+    SpinRate,
+    Extension,
+    VerticalAboveSlot,
+    HorizontalAboveSlot,
+    VelocityDifference,
+    MovementDifference,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub(crate) struct StuffTerm {
+    pub feature: StuffFeature,
+    pub mean: f32,
+    pub sd: f32,
+    pub weight: f32,
+}
+
+/// Linear stuff model over standardized features, built in from [`STUFF_MODEL_PATH`].
+#[derive(Debug, Clone, PartialEq, Resource, Deserialize)]
+pub(crate) struct StuffModel {
+    pub baseline: f32,
+    pub scale: f32,
+    /// meters
+    pub shoulder_height: f32,
+    /// inches
+    pub expected_fastball_break: f32,
+    /// degrees
+    pub slot_offset: f32,
+    pub fastball: Vec<StuffTerm>,
+    pub offspeed: Vec<StuffTerm>,
+}
+
+impl Default for StuffModel {
+    fn default() -> Self {
+        ron::from_str(include_str!("../../assets/data/stuff_model.ron"))
+            .expect("invalid built-in stuff model")
+    }
+}
+
+impl StuffModel {
+    /// Reads the model from the asset folder, so edits can be tried without rebuilding.
+    /// The web build has no file system and only has the built-in model.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn load() -> Result<Self, Error> {
+        let path = bevy::asset::io::file::FileAssetReader::get_base_path()
+            .join("assets")
+            .join(STUFF_MODEL_PATH);
+        let text = std::fs::read_to_string(&path)
+            .map_err(|e| Error::GenericError(format!("{}: {}", path.display(), e)))?;
+        ron::from_str(&text).map_err(|e| Error::GenericError(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Fastball movement the arm slot predicts, in inches.
+    fn slot_movement(&self, release_point: Vec3) -> Vec2 {
+        let arm_angle = (release_point.y - self.shoulder_height).atan2(release_point.x.abs());
+        Vec2::from_angle(arm_angle + self.slot_offset.to_radians()) * self.expected_fastball_break
+    }
+
+    fn feature(
+        &self,
+        feature: StuffFeature,
+        pitch: &StuffPitch,
+        fastball: Option<&StuffPitch>,
+    ) -> Option<f32> {
+        let movement = pitch.movement_inches();
+        Some(match feature {
+            StuffFeature::Velocity => pitch.params.speed,
+            StuffFeature::SpinRate => pitch.params.spin_rate,
+            StuffFeature::Extension => pitch.extension * M_TO_FEET,
+            StuffFeature::VerticalAboveSlot => {
+                movement.y - self.slot_movement(pitch.release_point).y
+            }
+            StuffFeature::HorizontalAboveSlot => {
+                movement.x - self.slot_movement(pitch.release_point).x
+            }
+            StuffFeature::VelocityDifference => fastball?.params.speed - pitch.params.speed,
+            StuffFeature::MovementDifference => fastball?.movement_inches().distance(movement),
+        })
+    }
+
+    /// Grades `pitch`. Terms comparing against the primary fastball are left out
+    /// when there is none.
+    pub(crate) fn score(&self, pitch: &StuffPitch, fastball: Option<&StuffPitch>) -> f32 {
+        let terms = if pitch.class.is_fastball() {
+            &self.fastball
+        } else {
+            &self.offspeed
+        };
+        let total: f32 = terms
+            .iter()
+            .filter_map(|term| {
+                let value = self.feature(term.feature, pitch, fastball)?;
+                let z = ((value - term.mean) / term.sd.max(f32::EPSILON))
+                    .clamp(-MAX_Z_SCORE, MAX_Z_SCORE);
+                Some(term.weight * z)
+            })
+            .sum();
+        self.baseline + self.scale * total
+    }
+}
+
+/// What the stuff model needs to know about a pitch.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StuffPitch {
+    pub params: PitchParams,
+    pub class: PitchClass,
+    pub movement: PitchMovement,
+    pub release_point: Vec3,
+    /// how far in front of the rubber the ball is released, in meters
+    pub extension: f32,
+}
+
+impl StuffPitch {
+    pub(crate) fn new(
+        params: PitchParams,
+        class: PitchClass,
+        movement: PitchMovement,
+        field: &FieldGeometry,
+    ) -> Self {
+        let release_point = params.release_point(field);
+        Self {
+            params,
+            class,
+            movement,
+            release_point,
+            extension: field.pitching_distance - release_point.z,
+        }
+    }
+
+    /// Arm-side horizontal and induced vertical break in inches.
+    fn movement_inches(&self) -> Vec2 {
+        Vec2::new(
+            self.movement.horizontal_break,
+            self.movement.induced_vertical_break,
+        ) * M_TO_INCHES
+    }
+}
+
+/// The most common fastball type among `pitches`, averaged. Ties go to the faster type.
+pub(crate) fn primary_fastball<'a>(
+    pitches: impl Iterator<Item = &'a StuffPitch>,
+) -> Option<StuffPitch> {
+    let fastballs: Vec<&StuffPitch> = pitches.filter(|pitch| pitch.class.is_fastball()).collect();
+    let count = |class: PitchClass| fastballs.iter().filter(|p| p.class == class).count();
+    let mean_speed = |class: PitchClass| {
+        let speeds = fastballs
+            .iter()
+            .filter(|p| p.class == class)
+            .map(|p| p.params.speed);
+        speeds.clone().sum::<f32>() / speeds.count().max(1) as f32
+    };
+    let class = fastballs.iter().map(|pitch| pitch.class).max_by(|a, b| {
+        count(*a)
+            .cmp(&count(*b))
+            .then(mean_speed(*a).total_cmp(&mean_speed(*b)))
+    })?;
+
+    let of_class: Vec<&StuffPitch> = fastballs.into_iter().filter(|p| p.class == class).collect();
+    let n = of_class.len() as f32;
+    let mut primary = *of_class[0];
+    primary.params.speed = of_class.iter().map(|p| p.params.speed).sum::<f32>() / n;
+    primary.movement = PitchMovement {
+        induced_vertical_break: of_class
+            .iter()
+            .map(|p| p.movement.induced_vertical_break)
+            .sum::<f32>()
+            / n,
+        horizontal_break: of_class
+            .iter()
+            .map(|p| p.movement.horizontal_break)
+            .sum::<f32>()
+            / n,
+    };
+    Some(primary)
+}
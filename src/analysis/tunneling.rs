@@ -0,0 +1,314 @@
+use crate::prelude::*;
+
+const TUNNEL_PATH_COLORS: [Color; 2] = [Color::srgb(0.2, 0.6, 1.0), Color::srgb(1.0, 0.45, 0.2)];
+const TUNNEL_POINT_COLOR: Color = Color::WHITE;
+
+#[derive(Component, Debug)]
+pub(crate) struct TunnelPathMarker;
+
+#[derive(Debug, Clone)]
+pub(crate) struct TunnelPitch {
+    pub name: String,
+    pub trajectory: Trajectory,
+    pub tunnel_point: Option<Vec3>,
+    pub plate_location: Option<Vec3>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct TunnelResult {
+    pub pitches: [TunnelPitch; 2],
+    /// depth of the decision point in meters
+    pub tunnel_z: f32,
+}
+
+impl TunnelResult {
+    fn separation(a: Option<Vec3>, b: Option<Vec3>) -> Option<f32> {
+        Some(a?.distance(b?))
+    }
+
+    /// Separation of the two pitches when the batter has to decide whether to swing.
+    pub(crate) fn tunnel_separation(&self) -> Option<f32> {
+        let [a, b] = &self.pitches;
+        Self::separation(a.tunnel_point, b.tunnel_point)
+    }
+
+    pub(crate) fn plate_separation(&self) -> Option<f32> {
+        let [a, b] = &self.pitches;
+        Self::separation(a.plate_location, b.plate_location)
+    }
+}
+
+/// Compares two presets thrown at the same target: how close they stay until the batter
+/// has to decide, and how far apart they finish.
+#[derive(Debug, Resource)]
+pub(crate) struct TunnelingAnalysis {
+    pub open: bool,
+    pub presets: [Option<String>; 2],
+    /// shared target at the front of home plate, x and height in meters
+    pub target: Vec2,
+    /// distance of the decision point from the front of home plate in feet
+    pub decision_distance: f32,
+    pub result: Option<TunnelResult>,
+    /// set when the drawn paths no longer match `result`
+    paths_outdated: bool,
+}
+
+impl Default for TunnelingAnalysis {
+    fn default() -> Self {
+        Self {
+            open: false,
+            presets: [None, None],
+            target: Vec2::new(0., 0.75),
+            decision_distance: 23.8,
+            result: None,
+            paths_outdated: false,
+        }
+    }
+}
+
+pub(crate) fn analyze_tunnel(
+    pitches: [&PitchPreset; 2],
+    target: Vec2,
+    decision_distance: f32,
+    field: &FieldGeometry,
+    environment: &AeroEnvironment,
+    profile: &AeroProfile,
+    effects: &AeroEffects,
+) -> TunnelResult {
+    let target = target.extend(DEFAULT_FRONT_PANEL_POS_Z);
+    let tunnel_z = DEFAULT_FRONT_PANEL_POS_Z + decision_distance / M_TO_FEET;
+    TunnelResult {
+        pitches: pitches.map(|preset| {
+            let direction = (target - preset.params.release_point(field)).normalize();
+            let trajectory = simulate_pitch(
+                &preset.params,
+                direction,
+                field,
+                environment,
+                profile,
+                effects,
+            );
+            TunnelPitch {
+                name: preset.name.clone(),
+                tunnel_point: trajectory.position_at_z(tunnel_z),
+                plate_location: trajectory.plate_location(),
+                trajectory,
+            }
+        }),
+        tunnel_z,
+    }
+}
+
+fn format_separation(separation: Option<f32>, unit: LengthUnit) -> String {
+    match separation {
+        Some(separation) => unit.format(separation, 1),
+        None => "-".to_string(),
+    }
+}
+
+pub(crate) fn tunneling_window(
+    mut contexts: EguiContexts,
+    mut tunneling_analysis: ResMut<TunnelingAnalysis>,
+    pitch_presets: Res<PitchPresets>,
+    field: Res<FieldGeometry>,
+    aero_environment: Res<AeroEnvironment>,
+    aero_profile: Res<AeroProfile>,
+    aero_effects: Res<AeroEffects>,
+    units: Res<Units>,
+) {
+    let ctx = contexts.ctx_mut();
+    let tunneling_analysis = tunneling_analysis.as_mut();
+
+    let mut open = tunneling_analysis.open;
+    egui::Window::new("tunneling")
+        .open(&mut open)
+        .show(ctx, |ui| {
+            egui::Grid::new("tunneling inputs")
+                .spacing([20.0, 10.0])
+                .show(ui, |ui| {
+                    for (i, selected) in tunneling_analysis.presets.iter_mut().enumerate() {
+                        ui.label(format!("pitch {}", i + 1));
+                        egui::ComboBox::from_id_salt(("tunnel preset", i))
+                            .selected_text(selected.as_deref().unwrap_or("-"))
+                            .show_ui(ui, |ui| {
+                                for preset in pitch_presets.0.iter() {
+                                    ui.selectable_value(
+                                        selected,
+                                        Some(preset.name.clone()),
+                                        &preset.name,
+                                    );
+                                }
+                            });
+                        ui.end_row();
+                    }
+
+                    ui.label(unit_label("target side", units.length));
+                    unit_slider(
+                        ui,
+                        &mut tunneling_analysis.target.x,
+                        -15. / M_TO_INCHES..=15. / M_TO_INCHES,
+                        units.length,
+                    );
+                    ui.end_row();
+
+                    ui.label(unit_label("target height", units.length));
+                    unit_slider(
+                        ui,
+                        &mut tunneling_analysis.target.y,
+                        6. / M_TO_INCHES..=54. / M_TO_INCHES,
+                        units.length,
+                    );
+                    ui.end_row();
+
+                    // the decision distance is kept in feet
+                    ui.label(unit_label("decision point from plate", units.distance));
+                    let mut decision_distance = tunneling_analysis.decision_distance / M_TO_FEET;
+                    if unit_slider(
+                        ui,
+                        &mut decision_distance,
+                        10. / M_TO_FEET..=40. / M_TO_FEET,
+                        units.distance,
+                    )
+                    .changed()
+                    {
+                        tunneling_analysis.decision_distance = decision_distance * M_TO_FEET;
+                    }
+                    ui.end_row();
+                });
+
+            ui.add_space(10.0);
+            let selected = tunneling_analysis.presets.clone().map(|name| {
+                let name = name?;
+                pitch_presets.0.iter().find(|preset| preset.name == name)
+            });
+            ui.horizontal(|ui| {
+                if let [Some(first), Some(second)] = selected {
+                    if ui.button("Compare").clicked() {
+                        tunneling_analysis.result = Some(analyze_tunnel(
+                            [first, second],
+                            tunneling_analysis.target,
+                            tunneling_analysis.decision_distance,
+                            &field,
+                            &aero_environment,
+                            &aero_profile,
+                            &aero_effects,
+                        ));
+                        tunneling_analysis.paths_outdated = true;
+                    }
+                } else {
+                    ui.add_enabled(false, egui::Button::new("Compare"));
+                }
+                if ui.button("Clear").clicked() {
+                    tunneling_analysis.result = None;
+                    tunneling_analysis.paths_outdated = true;
+                }
+            });
+
+            let Some(result) = &tunneling_analysis.result else {
+                return;
+            };
+            ui.add_space(10.0);
+            egui::Grid::new("tunneling result")
+                .spacing([20.0, 5.0])
+                .show(ui, |ui| {
+                    for (pitch, color) in result.pitches.iter().zip(TUNNEL_PATH_COLORS) {
+                        ui.colored_label(egui_color(color), &pitch.name);
+                        ui.end_row();
+                    }
+                    ui.label("separation at decision point");
+                    ui.label(format_separation(result.tunnel_separation(), units.length));
+                    ui.end_row();
+                    ui.label("separation at plate");
+                    ui.label(format_separation(result.plate_separation(), units.length));
+                    ui.end_row();
+                });
+        });
+    tunneling_analysis.open = open;
+}
+
+/// Redraws the compared paths after each comparison, and only shows them while the tool is open.
+pub(crate) fn sync_tunnel_paths<T: GameScene>(
+    mut commands: Commands,
+    scene: Res<State<T>>,
+    mut tunneling_analysis: ResMut<TunnelingAnalysis>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut line_materials: ResMut<Assets<LineMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut query_paths: Query<(Entity, &mut Visibility), With<TunnelPathMarker>>,
+) {
+    if tunneling_analysis.paths_outdated {
+        tunneling_analysis.paths_outdated = false;
+        for (entity, _) in query_paths.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        let Some(result) = &tunneling_analysis.result else {
+            return;
+        };
+
+        for (pitch, color) in result.pitches.iter().zip(TUNNEL_PATH_COLORS) {
+            commands.spawn((
+                MaterialMeshBundle {
+                    mesh: meshes.add(LineStrip {
+                        points: pitch.trajectory.positions().collect(),
+                    }),
+                    material: line_materials.add(LineMaterial {
+                        color: color.into(),
+                    }),
+                    ..default()
+                },
+                TunnelPathMarker,
+                GameSceneMarker(scene.get().clone()),
+            ));
+            if let Some(tunnel_point) = pitch.tunnel_point {
+                commands.spawn((
+                    PbrBundle {
+                        mesh: meshes.add(Sphere::new(BASEBALL_RADIUS)),
+                        material: materials.add(StandardMaterial {
+                            base_color: color,
+                            unlit: true,
+                            ..default()
+                        }),
+                        transform: Transform::from_translation(tunnel_point),
+                        ..default()
+                    },
+                    TunnelPathMarker,
+                    GameSceneMarker(scene.get().clone()),
+                ));
+            }
+        }
+
+        // ring marking the decision point, centered between the two pitches
+        let [a, b] = &result.pitches;
+        if let (Some(a), Some(b)) = (a.tunnel_point, b.tunnel_point) {
+            let center = (a + b) / 2.;
+            let radius = (a.distance(b) / 2.).max(BASEBALL_RADIUS * 2.);
+            let points = (0..=32)
+                .map(|i| {
+                    let angle = i as f32 / 32. * std::f32::consts::TAU;
+                    center + Vec3::new(angle.cos(), angle.sin(), 0.) * radius
+                })
+                .collect();
+            commands.spawn((
+                MaterialMeshBundle {
+                    mesh: meshes.add(LineStrip { points }),
+                    material: line_materials.add(LineMaterial {
+                        color: TUNNEL_POINT_COLOR.into(),
+                    }),
+                    ..default()
+                },
+                TunnelPathMarker,
+                GameSceneMarker(scene.get().clone()),
+            ));
+        }
+        return;
+    }
+
+    let visibility = if tunneling_analysis.open {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for (_, mut path_visibility) in query_paths.iter_mut() {
+        path_visibility.set_if_neq(visibility);
+    }
+}
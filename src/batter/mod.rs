@@ -14,7 +14,7 @@ impl<T: GameScene> Plugin for BatterPlugin<T> {
     fn build(&self, app: &mut App) {
         app.register_type::<BatterCameraMarker>();
 
-        app.add_systems(OnEnter(self.scene.clone()), setup_batter_camera);
+        app.add_systems(OnEnter(self.scene.clone()), setup_batter_camera::<T>);
     }
 }
 
@@ -22,7 +22,7 @@ impl<T: GameScene> Plugin for BatterPlugin<T> {
 #[reflect(Component)]
 pub(crate) struct BatterCameraMarker;
 
-pub(crate) fn setup_batter_camera(mut commands: Commands) {
+pub(crate) fn setup_batter_camera<T: GameScene>(mut commands: Commands, scene: Res<State<T>>) {
     commands.spawn((
         Name::new("batter cam"),
         Camera3dBundle {
@@ -36,5 +36,7 @@ pub(crate) fn setup_batter_camera(mut commands: Commands) {
             ..default()
         },
         BatterCameraMarker,
+        SelectableCamera::new("batter", 1),
+        GameSceneMarker(scene.get().clone()),
     ));
 }
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+
+/// A camera the [`CameraSelector`] can switch to. Cameras are cycled by `order`.
+#[derive(Debug, Component, Reflect, Clone)]
+#[reflect(Component)]
+pub(crate) struct SelectableCamera {
+    pub label: String,
+    pub order: i32,
+}
+
+impl SelectableCamera {
+    pub(crate) fn new(label: impl Into<String>, order: i32) -> Self {
+        Self {
+            label: label.into(),
+            order,
+        }
+    }
+}
+
+/// One of the fixed broadcast cameras, placed around the current field.
+#[derive(Debug, Component, Reflect, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component)]
+pub(crate) enum BroadcastCamera {
+    /// behind the mound and a little to the first-base side, on a long lens
+    CenterField,
+    /// crouched behind the plate
+    Catcher,
+    FirstBaseSide,
+    ThirdBaseSide,
+    /// straight down with the mound at the top, below the ceiling
+    Overhead,
+}
+
+impl BroadcastCamera {
+    pub(crate) const ALL: [BroadcastCamera; 5] = [
+        BroadcastCamera::CenterField,
+        BroadcastCamera::Catcher,
+        BroadcastCamera::FirstBaseSide,
+        BroadcastCamera::ThirdBaseSide,
+        BroadcastCamera::Overhead,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            BroadcastCamera::CenterField => "center field",
+            BroadcastCamera::Catcher => "catcher",
+            BroadcastCamera::FirstBaseSide => "first base side",
+            BroadcastCamera::ThirdBaseSide => "third base side",
+            BroadcastCamera::Overhead => "overhead",
+        }
+    }
+
+    pub(crate) fn order(&self) -> i32 {
+        match self {
+            BroadcastCamera::CenterField => 2,
+            BroadcastCamera::Catcher => 3,
+            BroadcastCamera::FirstBaseSide => 4,
+            BroadcastCamera::ThirdBaseSide => 5,
+            BroadcastCamera::Overhead => 6,
+        }
+    }
+}
+
+/// The camera that follows the ball in flight and orbits its path afterward.
+#[derive(Debug, Component, Reflect)]
+#[reflect(Component)]
+pub(crate) struct BallCameraMarker;
@@ -0,0 +1,108 @@
+use crate::prelude::*;
+use bevy::ecs::system::SystemParam;
+
+mod components;
+mod resources;
+mod systems;
+
+use systems::*;
+
+pub(crate) mod prelude {
+    pub(crate) use super::*;
+    pub(crate) use components::*;
+    pub(crate) use resources::*;
+}
+
+/// Camera resources, for menus that pick and tune the cameras.
+#[derive(SystemParam)]
+pub(crate) struct CameraControls<'w> {
+    camera_selector: ResMut<'w, CameraSelector>,
+    ball_camera: ResMut<'w, BallCamera>,
+}
+
+impl CameraControls<'_> {
+    /// Grid rows picking the camera and setting up the ball camera.
+    pub(crate) fn settings(&mut self, ui: &mut egui::Ui) {
+        ui.label("camera");
+        let mut active = self.camera_selector.active;
+        egui::ComboBox::from_id_salt("camera")
+            .selected_text(self.camera_selector.active_label())
+            .show_ui(ui, |ui| {
+                for (entity, label) in self.camera_selector.cameras.iter() {
+                    ui.selectable_value(&mut active, Some(*entity), label);
+                }
+            });
+        if active != self.camera_selector.active {
+            self.camera_selector.active = active;
+        }
+        ui.end_row();
+
+        ui.label("layout");
+        let mut layout = self.camera_selector.layout;
+        egui::ComboBox::from_id_salt("layout")
+            .selected_text(layout.label())
+            .show_ui(ui, |ui| {
+                for option in ViewLayout::ALL {
+                    ui.selectable_value(&mut layout, option, option.label());
+                }
+            });
+        if layout != self.camera_selector.layout {
+            self.camera_selector.layout = layout;
+        }
+        ui.end_row();
+
+        ui.label("ball camera lead (m)");
+        egui::Slider::new(&mut self.ball_camera.lead_distance, -5.0_f32..=5.0_f32).ui(ui);
+        ui.end_row();
+    }
+}
+
+/// Fixed broadcast cameras around the field, and the [`CameraSelector`] that switches
+/// between every camera tagged [`SelectableCamera`], whichever plugin spawned it.
+#[derive(Debug)]
+pub(crate) struct CamerasPlugin<T: GameScene> {
+    pub scene: T,
+    pub render_layers: Vec<usize>,
+}
+
+impl<T: GameScene> Plugin for CamerasPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SelectableCamera>()
+            .register_type::<BroadcastCamera>()
+            .register_type::<BallCameraMarker>();
+
+        app.insert_resource(CamerasPluginConfig {
+            render_layers: self.render_layers.clone(),
+        })
+        .init_resource::<CameraSelector>()
+        .init_resource::<BallCamera>();
+
+        app.add_systems(
+            OnEnter(self.scene.clone()),
+            (
+                reset_resource::<CameraSelector>,
+                reset_resource::<BallCamera>,
+                spawn_broadcast_cameras::<T>,
+                spawn_ball_camera::<T>,
+            )
+                .in_set(GameScenesSet::OnEnterSet(self.scene.clone())),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                place_broadcast_cameras,
+                (register_cameras, apply_camera_selection).chain(),
+            )
+                .in_set(GameScenesSet::UpdateSet(self.scene.clone())),
+        )
+        .add_systems(
+            Update,
+            (follow_ball, orbit_ball_camera, draw_ball_track)
+                .chain()
+                // after the flight systems rather than among them, so it keeps orbiting while paused
+                .after(UpdateBaseballFlightStateSet::PostUpdate)
+                .in_set(GameScenesSet::UpdateSet(self.scene.clone())),
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Resource)]
+pub(crate) struct CamerasPluginConfig {
+    pub render_layers: Vec<usize>,
+}
+
+/// Cameras shown alongside the active one, most wanted first. The catcher's view shows
+/// location and the first-base side shows vertical break.
+const INSET_LABELS: [&str; 5] = [
+    "catcher",
+    "first base side",
+    "overhead",
+    "center field",
+    "third base side",
+];
+
+/// How the window is shared between cameras.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub(crate) enum ViewLayout {
+    #[default]
+    Single,
+    /// the active camera fills the window, with small insets along the right edge
+    PictureInPicture,
+    /// four cameras, one per quarter of the window
+    Grid,
+}
+
+impl ViewLayout {
+    pub(crate) const ALL: [ViewLayout; 3] = [
+        ViewLayout::Single,
+        ViewLayout::PictureInPicture,
+        ViewLayout::Grid,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            ViewLayout::Single => "single",
+            ViewLayout::PictureInPicture => "picture-in-picture",
+            ViewLayout::Grid => "2x2 grid",
+        }
+    }
+
+    /// Regions of the window as fractions, top-left origin, the active camera's first.
+    /// Later regions are drawn over earlier ones.
+    pub(crate) fn regions(&self) -> Vec<Rect> {
+        match self {
+            ViewLayout::Single => vec![Rect::new(0., 0., 1., 1.)],
+            ViewLayout::PictureInPicture => vec![
+                Rect::new(0., 0., 1., 1.),
+                Rect::new(0.68, 0.02, 0.98, 0.32),
+                Rect::new(0.68, 0.34, 0.98, 0.64),
+            ],
+            ViewLayout::Grid => vec![
+                Rect::new(0., 0., 0.5, 0.5),
+                Rect::new(0.5, 0., 1., 0.5),
+                Rect::new(0., 0.5, 0.5, 1.),
+                Rect::new(0.5, 0.5, 1., 1.),
+            ],
+        }
+    }
+}
+
+/// Every [`SelectableCamera`] in the scene, in cycling order, and the one being looked through.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct CameraSelector {
+    pub cameras: Vec<(Entity, String)>,
+    pub active: Option<Entity>,
+    pub layout: ViewLayout,
+}
+
+impl CameraSelector {
+    /// Moves `step` cameras along, wrapping around at either end.
+    pub(crate) fn cycle(&mut self, step: i32) {
+        if self.cameras.is_empty() {
+            return;
+        }
+        let current = self
+            .active
+            .and_then(|active| {
+                self.cameras
+                    .iter()
+                    .position(|(entity, _)| *entity == active)
+            })
+            .unwrap_or(0) as i32;
+        let next = (current + step).rem_euclid(self.cameras.len() as i32) as usize;
+        self.active = Some(self.cameras[next].0);
+    }
+
+    /// The cameras on screen, in the order of the layout's regions.
+    pub(crate) fn views(&self) -> Vec<Entity> {
+        let Some(active) = self.active else {
+            return vec![];
+        };
+        let insets = INSET_LABELS.iter().filter_map(|wanted| {
+            self.cameras
+                .iter()
+                .find(|(entity, label)| label == wanted && *entity != active)
+                .map(|(entity, _)| *entity)
+        });
+        std::iter::once(active)
+            .chain(insets)
+            .take(self.layout.regions().len())
+            .collect()
+    }
+
+    pub(crate) fn active_label(&self) -> &str {
+        self.active
+            .and_then(|active| self.cameras.iter().find(|(entity, _)| *entity == active))
+            .map_or("-", |(_, label)| label.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum BallCameraMode {
+    /// behind the release point until a pitch is thrown
+    #[default]
+    Waiting,
+    Following,
+    /// around the path of the last pitch
+    Orbiting,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Orbit {
+    pub center: Vec3,
+    /// radians around the vertical, 0 looking from the mound side
+    pub yaw: f32,
+    /// radians above the horizon
+    pub pitch: f32,
+    /// meters
+    pub distance: f32,
+}
+
+impl Orbit {
+    pub(crate) fn transform(&self) -> Transform {
+        let offset = Vec3::new(
+            self.pitch.cos() * self.yaw.sin(),
+            self.pitch.sin(),
+            self.pitch.cos() * self.yaw.cos(),
+        ) * self.distance;
+        Transform::from_translation(self.center + offset).looking_at(self.center, Vec3::Y)
+    }
+}
+
+#[derive(Debug, Resource)]
+pub(crate) struct BallCamera {
+    pub mode: BallCameraMode,
+    /// how far behind the ball the camera rides in meters; negative rides ahead, looking back
+    pub lead_distance: f32,
+    /// the ball being followed, or whose path is being orbited
+    pub ball: Option<Entity>,
+    /// positions of the ball from release to the plate
+    pub track: Vec<Vec3>,
+    pub orbit: Orbit,
+}
+
+impl Default for BallCamera {
+    fn default() -> Self {
+        Self {
+            mode: BallCameraMode::default(),
+            lead_distance: 1.5,
+            ball: None,
+            track: vec![],
+            orbit: Orbit {
+                center: Vec3::ZERO,
+                yaw: 0.,
+                pitch: 0.,
+                distance: 1.,
+            },
+        }
+    }
+}
+
+impl BallCamera {
+    /// Orbits the recorded path, starting side-on from the first-base side
+    /// so the break is in full view.
+    pub(crate) fn start_orbit(&mut self) {
+        let (min, max) = self.track.iter().fold(
+            (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+            |(min, max), point| (min.min(*point), max.max(*point)),
+        );
+        if min.x > max.x {
+            self.mode = BallCameraMode::Waiting;
+            return;
+        }
+        self.mode = BallCameraMode::Orbiting;
+        self.orbit = Orbit {
+            center: (min + max) / 2.,
+            yaw: -std::f32::consts::FRAC_PI_2,
+            pitch: 0.2,
+            distance: (max - min).length() * 0.8 + 1.,
+        };
+    }
+}
@@ -0,0 +1,318 @@
+use crate::prelude::*;
+use bevy::input::mouse::MouseMotion;
+
+/// Home plate at knee height, where the fixed cameras look.
+const PLATE_TARGET: Vec3 = Vec3::new(0., 0.8, 0.2);
+/// how far past the front of the plate the ball is followed, in meters
+const FOLLOW_PAST_PLATE: f32 = 1.;
+/// how far above the ball's path the follow camera rides, in meters
+const FOLLOW_HEIGHT: f32 = 0.15;
+/// radians of orbit per pixel of mouse movement
+const ORBIT_SENSITIVITY: f32 = 0.005;
+/// radians of orbit per second at full stick
+const ORBIT_STICK_SPEED: f32 = 1.5;
+/// fraction of the orbit distance zoomed per scroll line
+const ORBIT_ZOOM_STEP: f32 = 0.1;
+const TRACK_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+const CHORD_COLOR: Color = Color::srgba(0.9, 0.9, 0.9, 0.5);
+
+/// Where `camera` stands and what it sees on `field`.
+fn broadcast_view(
+    camera: BroadcastCamera,
+    field: &FieldGeometry,
+) -> (Transform, blenvy::Projection) {
+    let distance = field.pitching_distance;
+    let mound = Vec3::new(0., 1.5, distance);
+    let perspective = |fov: f32| {
+        blenvy::Projection::Perspective(PerspectiveProjection {
+            fov: fov.to_radians(),
+            ..default()
+        })
+    };
+    match camera {
+        BroadcastCamera::CenterField => (
+            Transform::from_xyz(-0.5, 2.6, distance + 5.).looking_at(PLATE_TARGET, Vec3::Y),
+            perspective(12.),
+        ),
+        BroadcastCamera::Catcher => (
+            Transform::from_xyz(0., 0.85, -0.7).looking_at(mound, Vec3::Y),
+            perspective(40.),
+        ),
+        BroadcastCamera::FirstBaseSide => (
+            Transform::from_xyz(-4.5, 1.6, -1.5).looking_at(mound * 0.4, Vec3::Y),
+            perspective(60.),
+        ),
+        BroadcastCamera::ThirdBaseSide => (
+            Transform::from_xyz(4.5, 1.6, -1.5).looking_at(mound * 0.4, Vec3::Y),
+            perspective(60.),
+        ),
+        BroadcastCamera::Overhead => (
+            Transform::from_xyz(0., 3.5, distance / 2.)
+                .looking_at(Vec3::new(0., 0., distance / 2.), Vec3::Z),
+            blenvy::Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical(distance + 3.),
+                ..default()
+            }),
+        ),
+    }
+}
+
+/// Spawns the broadcast cameras. They start off; the pitcher camera is the first one looked through.
+pub(crate) fn spawn_broadcast_cameras<T: GameScene>(
+    mut commands: Commands,
+    scene: Res<State<T>>,
+    field: Res<FieldGeometry>,
+    cameras_plugin_config: Res<CamerasPluginConfig>,
+) {
+    for camera in BroadcastCamera::ALL {
+        let (transform, projection) = broadcast_view(camera, &field);
+        commands.spawn((
+            Name::new(format!("{} cam", camera.label())),
+            Camera3dBundle {
+                camera: Camera {
+                    is_active: false,
+                    ..default()
+                },
+                projection,
+                transform,
+                ..default()
+            },
+            RenderLayers::from_layers(&cameras_plugin_config.render_layers),
+            SelectableCamera::new(camera.label(), camera.order()),
+            camera,
+            GameSceneMarker(scene.get().clone()),
+        ));
+    }
+}
+
+/// Moves the broadcast cameras when the field changes.
+pub(crate) fn place_broadcast_cameras(
+    field: Res<FieldGeometry>,
+    mut query_camera: Query<(&BroadcastCamera, &mut Transform, &mut blenvy::Projection)>,
+) {
+    if !field.is_changed() {
+        return;
+    }
+    for (camera, mut transform, mut projection) in query_camera.iter_mut() {
+        (*transform, *projection) = broadcast_view(*camera, &field);
+    }
+}
+
+pub(crate) fn spawn_ball_camera<T: GameScene>(
+    mut commands: Commands,
+    scene: Res<State<T>>,
+    cameras_plugin_config: Res<CamerasPluginConfig>,
+) {
+    commands.spawn((
+        Name::new("ball cam"),
+        Camera3dBundle {
+            camera: Camera {
+                is_active: false,
+                ..default()
+            },
+            projection: blenvy::Projection::Perspective(PerspectiveProjection {
+                fov: 50.0_f32.to_radians(),
+                ..default()
+            }),
+            ..default()
+        },
+        RenderLayers::from_layers(&cameras_plugin_config.render_layers),
+        SelectableCamera::new("ball", 7),
+        BallCameraMarker,
+        GameSceneMarker(scene.get().clone()),
+    ));
+}
+
+/// Rides along with the ball from release to the plate, then hands over to the orbit.
+pub(crate) fn follow_ball(
+    mut ball_camera: ResMut<BallCamera>,
+    selected_pitch_parameters: Res<SelectedPitchParameters>,
+    field: Res<FieldGeometry>,
+    query_ball: Query<
+        (Entity, &Transform, &Velocity),
+        (With<AeroState>, Without<BallCameraMarker>),
+    >,
+    mut query_camera: Query<&mut Transform, With<BallCameraMarker>>,
+) {
+    let Ok(mut camera_transform) = query_camera.get_single_mut() else {
+        return;
+    };
+    // a ball whose path is already being orbited isn't picked up again if it bounces back
+    let in_flight = query_ball.iter().find(|(entity, transform, _)| {
+        transform.translation.z > DEFAULT_FRONT_PANEL_POS_Z - FOLLOW_PAST_PLATE
+            && !(ball_camera.mode == BallCameraMode::Orbiting && ball_camera.ball == Some(*entity))
+    });
+
+    match (in_flight, ball_camera.mode) {
+        (Some((entity, transform, velocity)), mode) => {
+            if mode != BallCameraMode::Following || ball_camera.ball != Some(entity) {
+                ball_camera.mode = BallCameraMode::Following;
+                ball_camera.ball = Some(entity);
+                ball_camera.track.clear();
+            }
+            ball_camera.track.push(transform.translation);
+            let direction = velocity.linvel.normalize_or_zero();
+            let eye = transform.translation - direction * ball_camera.lead_distance
+                + Vec3::Y * FOLLOW_HEIGHT;
+            *camera_transform =
+                Transform::from_translation(eye).looking_at(transform.translation, Vec3::Y);
+        }
+        (None, BallCameraMode::Following) => ball_camera.start_orbit(),
+        (None, BallCameraMode::Waiting) => {
+            let release_point = selected_pitch_parameters.0.release_point(&field);
+            *camera_transform =
+                Transform::from_translation(release_point + Vec3::new(0., 0.4, 2.5))
+                    .looking_at(PLATE_TARGET, Vec3::Y);
+        }
+        (None, BallCameraMode::Orbiting) => {}
+    }
+}
+
+/// Drag with the orbit button or push the right stick to orbit the last pitch's path, scroll to zoom.
+pub(crate) fn orbit_ball_camera(
+    mut contexts: EguiContexts,
+    mut ball_camera: ResMut<BallCamera>,
+    camera_selector: Res<CameraSelector>,
+    time: Res<Time<Real>>,
+    action_state: Res<ActionState<GameAction>>,
+    mut ev_mouse_motion: EventReader<MouseMotion>,
+    mut query_camera: Query<(Entity, &mut Transform), With<BallCameraMarker>>,
+) {
+    let mut motion: Vec2 = ev_mouse_motion.read().map(|ev| ev.delta).sum();
+    if !action_state.pressed(&GameAction::Orbit) {
+        motion = Vec2::ZERO;
+    }
+    // the stick turns at a steady rate, pushing up swings the camera up over the path
+    let stick = action_state.axis_pair(&GameAction::Look);
+    motion += stick * ORBIT_STICK_SPEED * time.delta_seconds() / ORBIT_SENSITIVITY;
+    let scroll = action_state.value(&GameAction::Zoom);
+    if ball_camera.mode != BallCameraMode::Orbiting {
+        return;
+    }
+    let Ok((camera, mut transform)) = query_camera.get_single_mut() else {
+        return;
+    };
+    // only steered while looking through it, and not while using the menu
+    let steering =
+        camera_selector.active == Some(camera) && !contexts.ctx_mut().wants_pointer_input();
+    if steering {
+        let orbit = &mut ball_camera.orbit;
+        orbit.yaw -= motion.x * ORBIT_SENSITIVITY;
+        orbit.pitch = (orbit.pitch + motion.y * ORBIT_SENSITIVITY).clamp(-1.4, 1.4);
+        orbit.distance = (orbit.distance * (1. - scroll * ORBIT_ZOOM_STEP)).clamp(0.5, 40.);
+    }
+    *transform = ball_camera.orbit.transform();
+}
+
+/// The last pitch's path, and the straight line from release to the plate it broke away from.
+pub(crate) fn draw_ball_track(
+    mut gizmos: Gizmos,
+    ball_camera: Res<BallCamera>,
+    camera_selector: Res<CameraSelector>,
+    query_camera: Query<Entity, With<BallCameraMarker>>,
+) {
+    let Ok(camera) = query_camera.get_single() else {
+        return;
+    };
+    if camera_selector.active != Some(camera) || ball_camera.mode != BallCameraMode::Orbiting {
+        return;
+    }
+    gizmos.linestrip(ball_camera.track.iter().copied(), TRACK_COLOR);
+    if let (Some(first), Some(last)) = (ball_camera.track.first(), ball_camera.track.last()) {
+        gizmos.line(*first, *last, CHORD_COLOR);
+    }
+}
+
+/// Keeps the selector's list in step with the cameras in the scene.
+pub(crate) fn register_cameras(
+    mut camera_selector: ResMut<CameraSelector>,
+    query_cameras: Query<(Entity, &SelectableCamera, &Camera)>,
+) {
+    let mut cameras: Vec<_> = query_cameras.iter().collect();
+    cameras.sort_by_key(|(_, selectable, _)| selectable.order);
+    let registered: Vec<(Entity, String)> = cameras
+        .iter()
+        .map(|(entity, selectable, _)| (*entity, selectable.label.clone()))
+        .collect();
+    if camera_selector.cameras != registered {
+        camera_selector.cameras = registered;
+    }
+
+    let active_exists = camera_selector
+        .active
+        .is_some_and(|active| query_cameras.contains(active));
+    if !active_exists {
+        // start from whichever camera is already on; the pitcher camera only shows up
+        // once the level is ready, so don't switch anything on before then
+        let active = cameras
+            .iter()
+            .find(|(_, _, camera)| camera.is_active)
+            .map(|(entity, _, _)| *entity);
+        if camera_selector.active != active {
+            camera_selector.active = active;
+        }
+    }
+}
+
+/// Switches on the cameras in the layout and fits each into its region of the window.
+/// Insets draw over the active camera, so they don't clear what's under them.
+pub(crate) fn apply_camera_selection(
+    camera_selector: Res<CameraSelector>,
+    primary_window: Query<Ref<Window>, With<PrimaryWindow>>,
+    mut query_cameras: Query<(Entity, &mut Camera), With<SelectableCamera>>,
+) {
+    let Ok(window) = primary_window.get_single() else {
+        return;
+    };
+    if !camera_selector.is_changed() && !window.is_changed() {
+        return;
+    }
+    let window_size = UVec2::new(window.physical_width(), window.physical_height()).as_vec2();
+    let views = camera_selector.views();
+    let regions = camera_selector.layout.regions();
+    for (entity, mut camera) in query_cameras.iter_mut() {
+        let Some(index) = views.iter().position(|view| *view == entity) else {
+            if camera.is_active {
+                camera.is_active = false;
+            }
+            continue;
+        };
+        let region = regions[index];
+        // the window changes with every cursor move, so only touch cameras whose region moved
+        let rect = (camera_selector.layout != ViewLayout::Single).then(|| {
+            (
+                (region.min * window_size).as_uvec2(),
+                (region.size() * window_size).as_uvec2().max(UVec2::ONE),
+            )
+        });
+        let current_rect = camera
+            .viewport
+            .as_ref()
+            .map(|viewport| (viewport.physical_position, viewport.physical_size));
+        if current_rect != rect {
+            camera.viewport = rect.map(|(physical_position, physical_size)| Viewport {
+                physical_position,
+                physical_size,
+                ..default()
+            });
+        }
+        let order = index as isize;
+        let clears = index == 0;
+        if !camera.is_active
+            || camera.order != order
+            || matches!(camera.clear_color, ClearColorConfig::None) == clears
+        {
+            camera.is_active = true;
+            camera.order = order;
+            camera.clear_color = if clears {
+                ClearColorConfig::Default
+            } else {
+                ClearColorConfig::None
+            };
+        }
+    }
+}
+
+pub(crate) fn cycle_camera(mut camera_selector: ResMut<CameraSelector>) {
+    camera_selector.cycle(1);
+}
@@ -0,0 +1,116 @@
+use crate::prelude::*;
+use bevy::ecs::system::SystemParam;
+use leafwing_input_manager::prelude::*;
+
+mod resources;
+mod systems;
+
+use systems::*;
+
+pub(crate) mod prelude {
+    pub(crate) use super::*;
+    pub(crate) use leafwing_input_manager::prelude::ActionState;
+    pub(crate) use resources::*;
+}
+
+pub(crate) fn action_just_pressed(
+    action: GameAction,
+) -> impl FnMut(Res<ActionState<GameAction>>) -> bool + Clone {
+    move |action_state| action_state.just_pressed(&action)
+}
+
+pub(crate) fn action_pressed(
+    action: GameAction,
+) -> impl FnMut(Res<ActionState<GameAction>>) -> bool + Clone {
+    move |action_state| action_state.pressed(&action)
+}
+
+pub(crate) fn action_just_released(
+    action: GameAction,
+) -> impl FnMut(Res<ActionState<GameAction>>) -> bool + Clone {
+    move |action_state| action_state.just_released(&action)
+}
+
+/// Control resources, for the menu that rebinds them.
+#[derive(SystemParam)]
+pub(crate) struct ControlBindings<'w> {
+    bindings: ResMut<'w, Bindings>,
+    rebinding: ResMut<'w, Rebinding>,
+}
+
+impl ControlBindings<'_> {
+    /// Grid rows listing each rebindable action and its buttons.
+    pub(crate) fn settings(&mut self, ui: &mut egui::Ui) {
+        for action in GameAction::BUTTONS {
+            ui.label(action.label());
+            let mut bound = self
+                .bindings
+                .get(action)
+                .iter()
+                .map(Binding::label)
+                .collect::<Vec<_>>();
+            if action == GameAction::ToggleMenu {
+                bound.insert(0, format!("{} (fixed)", Binding::Key(MENU_KEY).label()));
+            }
+            let bound = bound.join(", ");
+            ui.label(if bound.is_empty() {
+                "-".to_string()
+            } else {
+                bound
+            });
+            ui.horizontal(|ui| {
+                if self.rebinding.action == Some(action) {
+                    ui.label("press a button…");
+                } else if ui.button("add").clicked() {
+                    self.rebinding.action = Some(action);
+                    self.rebinding.status.clear();
+                }
+                if ui.button("clear").clicked() {
+                    self.bindings.clear(action);
+                    self.save();
+                }
+            });
+            ui.end_row();
+        }
+
+        if ui.button("restore defaults").clicked() {
+            *self.bindings = Bindings::default();
+            self.save();
+        }
+        ui.label(&self.rebinding.status);
+        ui.end_row();
+    }
+
+    fn save(&mut self) {
+        self.rebinding.action = None;
+        self.rebinding.status = match self.bindings.save() {
+            Ok(()) => "saved".to_string(),
+            Err(e) => e.to_string(),
+        };
+    }
+}
+
+/// Every input goes through [`GameAction`]s, bound from a saved file, with gamepad
+/// buttons alongside the keyboard and mouse.
+#[derive(Debug)]
+pub(crate) struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        let bindings = Bindings::load();
+
+        app.add_plugins(InputManagerPlugin::<GameAction>::default());
+
+        app.insert_resource(bindings.input_map())
+            .insert_resource(bindings)
+            .init_resource::<ActionState<GameAction>>()
+            .init_resource::<Rebinding>();
+
+        app.add_systems(
+            PreUpdate,
+            (capture_binding, apply_bindings)
+                .chain()
+                .after(bevy::input::InputSystem),
+        );
+    }
+}
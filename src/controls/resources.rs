@@ -0,0 +1,206 @@
+use crate::prelude::*;
+use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub(crate) const BINDINGS_FILE: &str = "bindings.ron";
+/// Always opens the menu and cancels rebinding, on top of whatever `ToggleMenu` is bound to,
+/// so clearing the menu's buttons can't lock the player out of it.
+pub(crate) const MENU_KEY: KeyCode = KeyCode::Escape;
+
+#[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub(crate) enum GameAction {
+    /// hold to aim and preview the pitch, release to throw
+    Throw,
+    ResetBall,
+    CycleCamera,
+    /// frees the cursor for the menu, or locks it again to aim
+    ToggleMenu,
+    /// drag to orbit the ball camera
+    Orbit,
+    Pause,
+    Step,
+    SlowDown,
+    SpeedUp,
+    /// aims the pitcher camera and orbits the ball camera from a gamepad
+    #[actionlike(DualAxis)]
+    Look,
+    #[actionlike(Axis)]
+    Zoom,
+}
+
+impl GameAction {
+    /// Actions bound to buttons, which can be rebound.
+    pub(crate) const BUTTONS: [GameAction; 9] = [
+        GameAction::Throw,
+        GameAction::ResetBall,
+        GameAction::CycleCamera,
+        GameAction::ToggleMenu,
+        GameAction::Orbit,
+        GameAction::Pause,
+        GameAction::Step,
+        GameAction::SlowDown,
+        GameAction::SpeedUp,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            GameAction::Throw => "aim and throw",
+            GameAction::ResetBall => "reset ball",
+            GameAction::CycleCamera => "next camera",
+            GameAction::ToggleMenu => "menu",
+            GameAction::Orbit => "orbit ball camera",
+            GameAction::Pause => "pause",
+            GameAction::Step => "step",
+            GameAction::SlowDown => "slower",
+            GameAction::SpeedUp => "faster",
+            GameAction::Look => "look",
+            GameAction::Zoom => "zoom",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButtonType),
+}
+
+impl Binding {
+    pub(crate) fn label(&self) -> String {
+        match self {
+            Binding::Key(key) => format!("{:?}", key),
+            Binding::Mouse(button) => format!("{:?} mouse", button),
+            Binding::Gamepad(button) => format!("{:?}", button),
+        }
+    }
+}
+
+/// Buttons bound to each [`GameAction`]. The right stick and the scroll wheel
+/// always drive `Look` and `Zoom`.
+#[derive(Debug, Resource, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Bindings {
+    pub actions: Vec<(GameAction, Vec<Binding>)>,
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        use Binding::*;
+        Self {
+            actions: vec![
+                (
+                    GameAction::Throw,
+                    vec![
+                        Mouse(MouseButton::Right),
+                        Gamepad(GamepadButtonType::RightTrigger2),
+                    ],
+                ),
+                (
+                    GameAction::ResetBall,
+                    vec![Key(KeyCode::KeyR), Gamepad(GamepadButtonType::West)],
+                ),
+                (
+                    GameAction::CycleCamera,
+                    vec![Key(KeyCode::KeyQ), Gamepad(GamepadButtonType::North)],
+                ),
+                (
+                    GameAction::ToggleMenu,
+                    vec![Gamepad(GamepadButtonType::Start)],
+                ),
+                (GameAction::Orbit, vec![Mouse(MouseButton::Left)]),
+                (
+                    GameAction::Pause,
+                    vec![Key(KeyCode::KeyP), Gamepad(GamepadButtonType::Select)],
+                ),
+                (
+                    GameAction::Step,
+                    vec![Key(KeyCode::Period), Gamepad(GamepadButtonType::DPadRight)],
+                ),
+                (
+                    GameAction::SlowDown,
+                    vec![
+                        Key(KeyCode::BracketLeft),
+                        Gamepad(GamepadButtonType::DPadDown),
+                    ],
+                ),
+                (
+                    GameAction::SpeedUp,
+                    vec![
+                        Key(KeyCode::BracketRight),
+                        Gamepad(GamepadButtonType::DPadUp),
+                    ],
+                ),
+            ],
+        }
+    }
+}
+
+impl Bindings {
+    pub(crate) fn get(&self, action: GameAction) -> &[Binding] {
+        self.actions
+            .iter()
+            .find(|(bound, _)| *bound == action)
+            .map_or(&[], |(_, bindings)| bindings.as_slice())
+    }
+
+    /// Adds `binding` to `action`, taking it away from any other action.
+    /// `MENU_KEY` can't be bound.
+    pub(crate) fn bind(&mut self, action: GameAction, binding: Binding) {
+        if binding == Binding::Key(MENU_KEY) {
+            return;
+        }
+        for (_, bindings) in self.actions.iter_mut() {
+            bindings.retain(|bound| *bound != binding);
+        }
+        match self.actions.iter_mut().find(|(bound, _)| *bound == action) {
+            Some((_, bindings)) => bindings.push(binding),
+            None => self.actions.push((action, vec![binding])),
+        }
+    }
+
+    pub(crate) fn clear(&mut self, action: GameAction) {
+        if let Some((_, bindings)) = self.actions.iter_mut().find(|(bound, _)| *bound == action) {
+            bindings.clear();
+        }
+    }
+
+    pub(crate) fn input_map(&self) -> InputMap<GameAction> {
+        let mut input_map = InputMap::default();
+        for (action, bindings) in self.actions.iter() {
+            for binding in bindings.iter() {
+                match *binding {
+                    Binding::Key(key) => input_map.insert(*action, key),
+                    Binding::Mouse(button) => input_map.insert(*action, button),
+                    Binding::Gamepad(button) => input_map.insert(*action, button),
+                };
+            }
+        }
+        input_map
+            .insert(GameAction::ToggleMenu, MENU_KEY)
+            .insert_dual_axis(GameAction::Look, GamepadStick::RIGHT)
+            .insert_axis(GameAction::Zoom, MouseScrollAxis::Y);
+        input_map
+    }
+
+    /// The saved bindings, or the defaults if there are none or they can't be read.
+    /// `MENU_KEY` is dropped from them, so a file saved before it was fixed, or edited
+    /// by hand, can't bind it to some other action.
+    pub(crate) fn load() -> Self {
+        let mut bindings: Self = storage::load(BINDINGS_FILE).unwrap_or_default();
+        for (_, bound) in bindings.actions.iter_mut() {
+            bound.retain(|binding| *binding != Binding::Key(MENU_KEY));
+        }
+        bindings
+    }
+
+    pub(crate) fn save(&self) -> Result<(), Error> {
+        storage::save(BINDINGS_FILE, self)
+    }
+}
+
+/// The action waiting for its next input in the rebinding menu.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct Rebinding {
+    pub action: Option<GameAction>,
+    pub status: String,
+}
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+pub(crate) fn apply_bindings(bindings: Res<Bindings>, mut input_map: ResMut<InputMap<GameAction>>) {
+    if bindings.is_changed() {
+        *input_map = bindings.input_map();
+    }
+}
+
+/// Binds the next key, mouse button or gamepad button pressed to the action being rebound.
+/// `MENU_KEY` cancels.
+pub(crate) fn capture_binding(
+    mut rebinding: ResMut<Rebinding>,
+    mut bindings: ResMut<Bindings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+) {
+    let Some(action) = rebinding.action else {
+        return;
+    };
+    if keys.just_pressed(MENU_KEY) {
+        rebinding.action = None;
+        rebinding.status = "cancelled".to_string();
+        return;
+    }
+    let binding = keys
+        .get_just_pressed()
+        .next()
+        .map(|key| Binding::Key(*key))
+        .or_else(|| {
+            mouse_buttons
+                .get_just_pressed()
+                .next()
+                .map(|button| Binding::Mouse(*button))
+        })
+        .or_else(|| {
+            gamepad_buttons
+                .get_just_pressed()
+                .next()
+                .map(|button| Binding::Gamepad(button.button_type))
+        });
+    let Some(binding) = binding else {
+        return;
+    };
+    bindings.bind(action, binding);
+    rebinding.action = None;
+    rebinding.status = match bindings.save() {
+        Ok(()) => format!("{} bound to {}", action.label(), binding.label()),
+        Err(e) => e.to_string(),
+    };
+}
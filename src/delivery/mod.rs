@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+mod resources;
+mod systems;
+
+use systems::*;
+
+pub(crate) mod prelude {
+    pub(crate) use super::*;
+    pub(crate) use resources::*;
+}
+
+/// Optional timing meter between pressing and releasing the throw.
+///
+/// Holding the throw winds up: the needle builds effort until it reaches the sweet spot,
+/// and keeps going past it. Effort scales the release speed, and letting go early or late
+/// lifts or drops the pitch. Scenes start the wind-up from their throw input, and release
+/// it when they launch the ball.
+#[derive(Debug)]
+pub(crate) struct DeliveryPlugin;
+
+impl Plugin for DeliveryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Delivery>();
+
+        app.add_systems(Update, wind_up_delivery);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// seconds the needle takes to sweep the whole meter
+pub(crate) const METER_SECONDS: f32 = 1.2;
+/// meter position of full effort and an on-time release
+pub(crate) const SWEET_SPOT: f32 = 0.75;
+/// share of the selected speed thrown with no effort at all
+pub(crate) const MIN_EFFORT_SPEED: f32 = 0.8;
+/// how far a fully early or late release lifts or drops the pitch, in radians
+pub(crate) const MAX_RELEASE_ANGLE: f32 = 0.05;
+/// releases closer to the sweet spot than this count as on time
+const ON_TIME_TOLERANCE: f32 = 0.15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub(crate) enum DeliveryMode {
+    /// released at exactly the selected speed, straight at the aim point
+    #[default]
+    Instant,
+    /// released through the timing meter
+    Timed,
+}
+
+impl DeliveryMode {
+    pub(crate) const ALL: [DeliveryMode; 2] = [DeliveryMode::Instant, DeliveryMode::Timed];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            DeliveryMode::Instant => "instant",
+            DeliveryMode::Timed => "timing meter",
+        }
+    }
+}
+
+/// How a timed delivery came out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DeliveryOutcome {
+    /// [0, 1], full effort throws the selected speed
+    pub effort: f32,
+    /// [-1, 1], negative is early, positive is late
+    pub timing: f32,
+}
+
+impl DeliveryOutcome {
+    /// The outcome of letting go with the needle at `needle`. Effort builds up to the sweet
+    /// spot; letting go before it is early, holding past it is late.
+    pub(crate) fn from_needle(needle: f32) -> Self {
+        let timing = if needle < SWEET_SPOT {
+            (needle - SWEET_SPOT) / SWEET_SPOT
+        } else {
+            (needle - SWEET_SPOT) / (1. - SWEET_SPOT)
+        };
+        Self {
+            effort: (needle / SWEET_SPOT).min(1.),
+            timing: timing.clamp(-1., 1.),
+        }
+    }
+
+    /// `pitch` as delivered. An early release sails high and a late one is pulled down.
+    pub(crate) fn apply(&self, pitch: &PitchParams) -> PitchParams {
+        let axis = pitch.direction.cross(Vec3::Y).normalize_or_zero();
+        let lift = Quat::from_axis_angle(axis, -self.timing * MAX_RELEASE_ANGLE);
+        PitchParams {
+            speed: pitch.speed * (MIN_EFFORT_SPEED + (1. - MIN_EFFORT_SPEED) * self.effort),
+            direction: lift * pitch.direction,
+            ..*pitch
+        }
+    }
+
+    pub(crate) fn label(&self) -> &'static str {
+        if self.timing < -ON_TIME_TOLERANCE {
+            "early"
+        } else if self.timing > ON_TIME_TOLERANCE {
+            "late"
+        } else {
+            "on time"
+        }
+    }
+}
+
+/// The timing meter of the delivery in progress, and how the last one came out.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct Delivery {
+    /// seconds the throw has been held, while winding up
+    pub held: Option<f32>,
+    pub last: Option<DeliveryOutcome>,
+}
+
+impl Delivery {
+    /// Needle position on the meter, [0, 1].
+    pub(crate) fn needle(&self) -> Option<f32> {
+        self.held.map(|held| (held / METER_SECONDS).min(1.))
+    }
+
+    /// Ends the wind-up, if there was one, and returns how it came out.
+    pub(crate) fn release(&mut self) -> Option<DeliveryOutcome> {
+        let outcome = self.needle().map(DeliveryOutcome::from_needle);
+        self.held = None;
+        if outcome.is_some() {
+            self.last = outcome;
+        }
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pitch() -> PitchParams {
+        PitchParams {
+            speed: 90.,
+            direction: Vec3::NEG_Z,
+            ..SelectedPitchParameters::default().0
+        }
+    }
+
+    #[test]
+    fn sweet_spot_is_full_effort_on_time() {
+        let outcome = DeliveryOutcome::from_needle(SWEET_SPOT);
+        assert_eq!(outcome.effort, 1.);
+        assert_eq!(outcome.timing, 0.);
+        assert_eq!(outcome.label(), "on time");
+
+        let delivered = outcome.apply(&pitch());
+        assert_eq!(delivered.speed, 90.);
+        assert!(delivered.direction.abs_diff_eq(Vec3::NEG_Z, 1e-6));
+    }
+
+    #[test]
+    fn effort_scales_speed() {
+        let outcome = DeliveryOutcome {
+            effort: 0.,
+            timing: 0.,
+        };
+        assert!((outcome.apply(&pitch()).speed - 90. * MIN_EFFORT_SPEED).abs() < 1e-4);
+        let outcome = DeliveryOutcome::from_needle(SWEET_SPOT / 2.);
+        assert!((outcome.effort - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn early_sails_high_and_late_is_pulled_down() {
+        let early = DeliveryOutcome::from_needle(0.);
+        let late = DeliveryOutcome::from_needle(1.);
+        assert_eq!(early.timing, -1.);
+        assert_eq!(late.timing, 1.);
+        assert_eq!(early.label(), "early");
+        assert_eq!(late.label(), "late");
+
+        let early = early.apply(&pitch()).direction;
+        let late = late.apply(&pitch()).direction;
+        assert!((early.y - MAX_RELEASE_ANGLE.sin()).abs() < 1e-4);
+        assert!((late.y + MAX_RELEASE_ANGLE.sin()).abs() < 1e-4);
+        assert!((early.length() - 1.).abs() < 1e-5);
+        // the release angle only lifts or drops the pitch
+        assert!(early.x.abs() < 1e-6 && late.x.abs() < 1e-6);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::prelude::*;
+
+const METER_SIZE: egui::Vec2 = egui::Vec2::new(240.0, 14.0);
+
+pub(crate) fn start_delivery(settings: Res<UserSettings>, mut delivery: ResMut<Delivery>) {
+    if settings.delivery == DeliveryMode::Timed {
+        delivery.held = Some(0.);
+    }
+}
+
+pub(crate) fn wind_up_delivery(time: Res<Time>, mut delivery: ResMut<Delivery>) {
+    if let Some(held) = delivery.held.as_mut() {
+        *held += time.delta_seconds();
+    }
+}
+
+/// The timing meter while winding up, and how the last delivery came out.
+pub(crate) fn delivery_meter(
+    mut contexts: EguiContexts,
+    settings: Res<UserSettings>,
+    delivery: Res<Delivery>,
+    thrown_pitch: Res<ThrownPitch>,
+    units: Res<Units>,
+) {
+    if settings.delivery != DeliveryMode::Timed {
+        return;
+    }
+    let ctx = contexts.ctx_mut();
+
+    egui::Area::new(egui::Id::new("delivery meter"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -40.0))
+        .interactable(false)
+        .show(ctx, |ui| {
+            let (rect, _) = ui.allocate_exact_size(METER_SIZE, egui::Sense::hover());
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 3.0, egui::Color32::from_black_alpha(160));
+            let x_at = |t: f32| rect.left() + rect.width() * t;
+            if let Some(needle) = delivery.needle() {
+                let filled = egui::Rect::from_min_max(
+                    rect.min,
+                    egui::pos2(x_at(needle.min(SWEET_SPOT)), rect.bottom()),
+                );
+                painter.rect_filled(filled, 3.0, egui::Color32::from_rgb(90, 170, 255));
+                painter.vline(
+                    x_at(needle),
+                    rect.y_range(),
+                    egui::Stroke::new(2.0, egui::Color32::WHITE),
+                );
+            }
+            painter.vline(
+                x_at(SWEET_SPOT),
+                rect.y_range(),
+                egui::Stroke::new(2.0, egui::Color32::from_rgb(90, 220, 120)),
+            );
+
+            if let Some(outcome) = delivery.last {
+                ui.label(format!(
+                    "{}, {:.0}% effort, {}",
+                    units.speed.format(thrown_pitch.0.speed * MPH_TO_MPS, 1),
+                    outcome.effort * 100.,
+                    outcome.label()
+                ));
+            }
+        });
+}
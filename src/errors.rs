@@ -1,3 +1,12 @@
+#[derive(Debug)]
 pub(crate) enum Error {
     GenericError(String),
 }
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::GenericError(message) => write!(f, "{}", message),
+        }
+    }
+}
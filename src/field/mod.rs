@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+mod resources;
+mod systems;
+
+use systems::*;
+
+pub(crate) mod prelude {
+    pub(crate) use super::*;
+    pub(crate) use resources::*;
+}
+
+/// Pitching distance, mound, release point and strike zone rules of the field being played on.
+///
+/// Pitches are released from the current [`FieldGeometry`], which is handed to every
+/// simulation; this plugin moves the pitcher camera onto its rubber and fits the level's
+/// mound to it.
+#[derive(Debug)]
+pub(crate) struct FieldPlugin<T: GameScene> {
+    pub scene: T,
+}
+
+impl<T: GameScene> Plugin for FieldPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<MoundBase>();
+
+        app.init_resource::<FieldGeometry>();
+
+        app.add_systems(
+            Update,
+            follow_rubber.in_set(GameScenesSet::UpdateSet(self.scene.clone())),
+        )
+        .add_systems(
+            Update,
+            fit_mound
+                .in_set(GameScenesSet::UpdateSet(self.scene.clone()))
+                .in_set(GltfBlueprintsSet::AfterSpawn),
+        );
+    }
+}
+
+/// Transform of the level's mound as the blueprint placed it, for a regulation field.
+#[derive(Debug, Component, Reflect)]
+#[reflect(Component)]
+pub(crate) struct MoundBase(pub Transform);
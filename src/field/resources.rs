@@ -0,0 +1,161 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// front of the rubber to the back tip of home plate on a regulation field, in meters (60.5 ft)
+pub(crate) const REGULATION_PITCHING_DISTANCE: f32 = 18.44;
+/// regulation mound height in meters (10 in)
+pub(crate) const REGULATION_MOUND_HEIGHT: f32 = 0.254;
+
+#[derive(Debug, Resource, Reflect, Clone, Copy, PartialEq)]
+pub(crate) struct FieldGeometry {
+    /// front of the rubber to the back tip of home plate, in meters
+    pub pitching_distance: f32,
+    /// in meters
+    pub mound_height: f32,
+    /// release point of a right-hander relative to the front of the rubber, in meters:
+    /// arm side, height above the mound and extension toward the plate
+    pub release: Vec3,
+    pub strikezone: StrikezoneRule,
+    /// lowest and highest a pitch may peak above the ground, in meters, where the rules
+    /// require an arc
+    pub arc_limits: Option<(f32, f32)>,
+}
+
+impl Default for FieldGeometry {
+    fn default() -> Self {
+        FieldPreset::Mlb.geometry()
+    }
+}
+
+impl FieldGeometry {
+    pub(crate) fn release_point(&self, pitching_arm: PitchingArm) -> Vec3 {
+        let side = match pitching_arm {
+            PitchingArm::Righty => 1.,
+            PitchingArm::Lefty => -1.,
+        };
+        Vec3::new(
+            self.release.x * side,
+            self.mound_height + self.release.y,
+            self.pitching_distance - self.release.z,
+        )
+    }
+
+    /// Where the pitcher camera looks from.
+    pub(crate) fn rubber(&self) -> Vec3 {
+        Vec3::new(0., 0.15, self.pitching_distance)
+    }
+
+    /// How high `trajectory` peaked, on a field that requires an arc.
+    pub(crate) fn delivery_arc(&self, trajectory: &Trajectory) -> Option<DeliveryArc> {
+        let (lowest, highest) = self.arc_limits?;
+        let peak = trajectory
+            .positions()
+            .map(|position| position.y)
+            .fold(f32::MIN, f32::max);
+        Some(DeliveryArc {
+            peak,
+            legal: (lowest..=highest).contains(&peak),
+        })
+    }
+}
+
+/// How high a pitch peaked, on a field with arc limits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DeliveryArc {
+    /// meters
+    pub peak: f32,
+    /// whether it peaked within the limits
+    pub legal: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum FieldPreset {
+    Mlb,
+    Youth46,
+    Youth50,
+    FastpitchSoftball,
+    SlowpitchSoftball,
+}
+
+impl FieldPreset {
+    pub(crate) const ALL: [FieldPreset; 5] = [
+        FieldPreset::Mlb,
+        FieldPreset::Youth46,
+        FieldPreset::Youth50,
+        FieldPreset::FastpitchSoftball,
+        FieldPreset::SlowpitchSoftball,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            FieldPreset::Mlb => "Baseball (60.5 ft)",
+            FieldPreset::Youth46 => "Youth (46 ft)",
+            FieldPreset::Youth50 => "Youth (50 ft)",
+            FieldPreset::FastpitchSoftball => "Fastpitch softball",
+            FieldPreset::SlowpitchSoftball => "Slowpitch softball",
+        }
+    }
+
+    pub(crate) fn ball(&self) -> BallPreset {
+        match self {
+            FieldPreset::Mlb => BallPreset::Mlb,
+            // Little League balls are the same size and weight
+            FieldPreset::Youth46 | FieldPreset::Youth50 => BallPreset::Mlb,
+            FieldPreset::FastpitchSoftball => BallPreset::FastpitchSoftball,
+            FieldPreset::SlowpitchSoftball => BallPreset::SlowpitchSoftball,
+        }
+    }
+
+    pub(crate) fn geometry(&self) -> FieldGeometry {
+        // armpits to the top of the knees
+        let armpits_to_knees = StrikezoneRule {
+            top: 0.72,
+            bottom: 0.29,
+        };
+        match self {
+            FieldPreset::Mlb => FieldGeometry {
+                pitching_distance: REGULATION_PITCHING_DISTANCE,
+                mound_height: REGULATION_MOUND_HEIGHT,
+                release: Vec3::new(0.48, 1.566, 1.676),
+                strikezone: StrikezoneRule::default(),
+                arc_limits: None,
+            },
+            // Little League majors, 12 year olds
+            FieldPreset::Youth46 => FieldGeometry {
+                pitching_distance: 46. / M_TO_FEET,
+                mound_height: 6. / 12. / M_TO_FEET,
+                release: Vec3::new(0.36, 1.25, 1.2),
+                strikezone: armpits_to_knees,
+                arc_limits: None,
+            },
+            // intermediate 50/70 division, 13 year olds
+            FieldPreset::Youth50 => FieldGeometry {
+                pitching_distance: 50. / M_TO_FEET,
+                mound_height: 8. / 12. / M_TO_FEET,
+                release: Vec3::new(0.4, 1.38, 1.35),
+                strikezone: armpits_to_knees,
+                arc_limits: None,
+            },
+            // flat circle, underhand windmill released at the hip
+            FieldPreset::FastpitchSoftball => FieldGeometry {
+                pitching_distance: 43. / M_TO_FEET,
+                mound_height: 0.,
+                release: Vec3::new(0.25, 0.6, 1.5),
+                strikezone: armpits_to_knees,
+                arc_limits: None,
+            },
+            // lobbed underhand; the pitch has to peak between 6 and 12 ft
+            FieldPreset::SlowpitchSoftball => FieldGeometry {
+                pitching_distance: 50. / M_TO_FEET,
+                mound_height: 0.,
+                release: Vec3::new(0.3, 0.85, 0.9),
+                // back shoulder to the top of the front knee
+                strikezone: StrikezoneRule {
+                    top: 0.8,
+                    bottom: 0.29,
+                },
+                arc_limits: Some((6. / M_TO_FEET, 12. / M_TO_FEET)),
+            },
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::prelude::*;
+
+/// name of the mound blueprint instance in the level
+const MOUND_NAME: &str = "PitchMound";
+/// softball pitchers throw from flat ground, but the rubber stays visible
+const MIN_MOUND_SCALE: f32 = 0.02;
+
+/// Moves the pitcher camera onto the rubber of the current field.
+pub(crate) fn follow_rubber(
+    field: Res<FieldGeometry>,
+    mut query_camera_target: Query<&mut Transform, With<ThirdPersonCameraTarget>>,
+) {
+    if !field.is_changed() {
+        return;
+    }
+    for mut transform in query_camera_target.iter_mut() {
+        transform.translation = field.rubber();
+    }
+}
+
+/// Slides the level's mound to the pitching distance and scales it to the mound height.
+pub(crate) fn fit_mound(
+    mut commands: Commands,
+    field: Res<FieldGeometry>,
+    query_new_mound: Query<(Entity, &Name, &Transform), Without<MoundBase>>,
+    mut query_mound: Query<(Ref<MoundBase>, &mut Transform)>,
+) {
+    for (entity, name, transform) in query_new_mound.iter() {
+        if name.as_str() == MOUND_NAME {
+            commands.entity(entity).insert(MoundBase(*transform));
+        }
+    }
+    for (base, mut transform) in query_mound.iter_mut() {
+        if !field.is_changed() && !base.is_added() {
+            continue;
+        }
+        let base = base.0;
+        let height_scale = (field.mound_height / REGULATION_MOUND_HEIGHT).max(MIN_MOUND_SCALE);
+        *transform = base
+            .with_translation(
+                base.translation
+                    + Vec3::Z * (field.pitching_distance - REGULATION_PITCHING_DISTANCE),
+            )
+            .with_scale(base.scale * Vec3::new(1., height_scale, 1.));
+    }
+}
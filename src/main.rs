@@ -1,10 +1,19 @@
+mod aerodynamics;
+mod analysis;
 mod batter;
+mod cameras;
+mod controls;
+mod delivery;
 mod errors;
+mod field;
 mod materials;
 mod pitcher;
+mod playback;
 mod prelude;
 mod scenes;
+mod settings;
 mod strikezone;
+mod units;
 
 use crate::prelude::*;
 use bevy::asset::AssetMetaCheck;
@@ -96,11 +105,20 @@ fn main() {
     app.add_plugins((BlenvyPlugin::default(), BlenvyExtensions));
 
     app.add_plugins(NoCameraPlayerPlugin);
+    let settings = UserSettings::load();
+    // aerodynamic forces are applied by AerodynamicsPlugin, so they can be toggled at runtime
     app.add_plugins(BaseballFlightPlugin {
-        ssw_on: true,
-        magnus_on: true,
-        drag_on: true,
+        ssw_on: false,
+        magnus_on: false,
+        drag_on: false,
     });
+    app.add_plugins(AerodynamicsPlugin {
+        effects: settings.effects,
+    });
+    app.add_plugins(SettingsPlugin { settings });
+    app.add_plugins(ControlsPlugin);
+    app.add_plugins(PlaybackPlugin);
+    app.add_plugins(DeliveryPlugin);
     app.add_plugins(ThirdPersonCameraPlugin);
 
     app.add_plugins(GameScenePlugin);
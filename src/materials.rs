@@ -59,3 +59,9 @@ impl From<LineStrip> for Mesh {
         .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, line.points)
     }
 }
+
+/// Converts a bevy color for use in egui widgets
+pub(crate) fn egui_color(color: Color) -> egui::Color32 {
+    let [r, g, b, a] = color.to_srgba().to_u8_array();
+    egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+}
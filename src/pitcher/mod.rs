@@ -13,8 +13,6 @@ pub(crate) mod prelude {
     pub(crate) use super::*;
     pub(crate) use components::*;
     pub(crate) use resources::*;
-    pub(crate) const PITCH_DEFAULT_STARTING_POINT_RIGHTY: Vec3 = Vec3::new(0.48, 1.82, 16.764);
-    pub(crate) const PITCH_DEFAULT_STARTING_POINT_LEFTY: Vec3 = Vec3::new(-0.48, 1.82, 16.764);
 }
 
 #[derive(Debug)]
@@ -34,40 +32,44 @@ impl<T: GameScene> Plugin for PitcherPlugin<T> {
         app.insert_resource(PitcherPluginConfig {
             render_layers: self.render_layers.clone(),
         })
-        .insert_resource(SelectedPitchParameters(PitchParams {
-            gyro_pole: GyroPole::default(),
-            spin_efficiency: 1.,
-            speed: 90., // 90. * MPH_TO_FTS,
-            spin_rate: 2000.,
-            seam_y_angle: 0.,
-            seam_z_angle: std::f32::consts::PI / 2.,
-            tilt: Tilt::from_hour_mintes(12, 0).expect("invalid initial tilt params".into()),
-            // starting_point: Vec3::new(0.48, 1.82, 16.764),
-            pitching_arm: PitchingArm::Righty,
-            direction: Vec3::ZERO,
-        }));
+        .init_resource::<SelectedPitchParameters>()
+        .init_resource::<ThrownPitch>()
+        .init_resource::<PitchPresets>();
         // app.add_systems(OnEnter(self.scene.clone()), spawn_arms);
 
-        app.add_systems(OnEnter(self.scene.clone()), spawn_pitcher);
+        app.add_systems(
+            OnEnter(self.scene.clone()),
+            (
+                reset_resource::<SelectedPitchParameters>,
+                reset_resource::<ThrownPitch>,
+                spawn_pitcher::<T>,
+            ),
+        );
 
         app.add_systems(
             Update,
-            setup_camera
+            setup_camera::<T>
                 .in_set(GameScenesSet::UpdateSet(self.scene.clone()))
                 .in_set(GltfBlueprintsSet::AfterSpawn),
         );
     }
 }
 
-pub fn spawn_pitcher(mut commands: Commands) {
+pub fn spawn_pitcher<T: GameScene>(
+    mut commands: Commands,
+    scene: Res<State<T>>,
+    field: Res<FieldGeometry>,
+) {
     commands.spawn((
-        TransformBundle::from_transform(Transform::from_translation(Vec3::new(0., 0.15, 18.44))),
+        TransformBundle::from_transform(Transform::from_translation(field.rubber())),
         PitcherCameraTargetMarker,
+        GameSceneMarker(scene.get().clone()),
     ));
 }
 
-pub fn setup_camera(
+pub fn setup_camera<T: GameScene>(
     mut commands: Commands,
+    scene: Res<State<T>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     pitcher_plugin_config: Res<PitcherPluginConfig>,
@@ -84,11 +86,11 @@ pub fn setup_camera(
                 .spawn((
                     ThirdPersonCamera {
                         aim_speed: 5.0,
-                        cursor_lock_toggle_enabled: true,
+                        // toggled through GameAction::ToggleMenu instead
+                        cursor_lock_toggle_enabled: false,
                         offset_enabled: true,
                         offset: Offset::new(0., 1.7),
                         zoom: Zoom::new(8., 15.),
-                        cursor_lock_key: KeyCode::Escape,
                         ..default()
                     },
                     Camera3dBundle {
@@ -106,6 +108,8 @@ pub fn setup_camera(
                     RenderLayers::from_layers(&pitcher_plugin_config.render_layers),
                     // RenderLayers::layer(0),
                     PitcherCameraMarker,
+                    SelectableCamera::new("pitcher", 0),
+                    GameSceneMarker(scene.get().clone()),
                     Name::new("pitcher camera"),
                     InheritedVisibility::VISIBLE,
                 ))
@@ -8,6 +8,34 @@ pub(crate) struct PitcherPluginConfig {
 #[derive(Debug, Resource, Clone, Copy)]
 pub(crate) struct SelectedPitchParameters(pub PitchParams);
 
+impl Default for SelectedPitchParameters {
+    fn default() -> Self {
+        Self(PitchParams {
+            gyro_pole: GyroPole::default(),
+            spin_efficiency: 1.,
+            speed: 90., // 90. * MPH_TO_FTS,
+            spin_rate: 2000.,
+            seam_y_angle: 0.,
+            seam_z_angle: std::f32::consts::PI / 2.,
+            tilt: Tilt::from_hour_mintes(12, 0).expect("invalid initial tilt params".into()),
+            // starting_point: Vec3::new(0.48, 1.82, 16.764),
+            pitching_arm: PitchingArm::Righty,
+            direction: Vec3::ZERO,
+            knuckleball_seed: None,
+        })
+    }
+}
+
+/// The last pitch as it was released, after the delivery had its say.
+#[derive(Debug, Resource, Clone, Copy)]
+pub(crate) struct ThrownPitch(pub PitchParams);
+
+impl Default for ThrownPitch {
+    fn default() -> Self {
+        Self(SelectedPitchParameters::default().0)
+    }
+}
+
 #[derive(Debug, Reflect, Copy, Clone)]
 pub(crate) struct PitchParams {
     // not a parameter controlled by user
@@ -27,6 +55,104 @@ pub(crate) struct PitchParams {
     pub gyro_pole: GyroPole,
     pub seam_y_angle: f32,
     pub seam_z_angle: f32,
+    /// thrown as a knuckleball, with wobble drawn from this seed
+    pub knuckleball_seed: Option<u64>,
+}
+
+impl PitchParams {
+    pub(crate) fn release_point(&self, field: &FieldGeometry) -> Vec3 {
+        field.release_point(self.pitching_arm)
+    }
+
+    /// angular velocity in rad/s, in bevy coordinates
+    pub(crate) fn release_spin(&self) -> Vec3 {
+        get_angular_velocity_from_parameters(
+            self.tilt,
+            self.spin_efficiency,
+            self.spin_rate,
+            self.gyro_pole,
+        )
+        .from_baseball_coord_to_bevy()
+    }
+
+    pub(crate) fn seam_rotation(&self) -> Quat {
+        seam_rotation(self.seam_y_angle, self.seam_z_angle)
+    }
+
+    pub(crate) fn knuckle(&self) -> Option<Knuckle> {
+        self.knuckleball_seed.map(Knuckle::from_seed)
+    }
+
+    pub(crate) fn release_state(&self, direction: Vec3) -> BallState {
+        BallState {
+            velocity: direction * self.speed * MPH_TO_MPS,
+            spin: self.release_spin(),
+            orientation: self.seam_rotation(),
+            knuckle: self.knuckle(),
+        }
+    }
+}
+
+/// Tilt from a position on the clock face in minutes, wrapping around 12:00.
+pub(crate) fn tilt_from_clock_minutes(minutes: f32) -> Tilt {
+    let minutes = minutes.rem_euclid(720.).floor() as i32;
+    let (hr, min) = (minutes / 60, minutes % 60);
+    Tilt::from_hour_mintes(if hr == 0 { 12 } else { hr as i8 }, min as i8)
+        .expect("invalid tilt params".into())
+}
+
+/// Position of the tilt on the clock face in minutes past 12:00.
+pub(crate) fn tilt_to_clock_minutes(tilt: Tilt) -> f32 {
+    let (hr, min) = tilt.to_hour_minutes();
+    ((hr as f32 % 12.) * 60. + min as f32).rem_euclid(720.)
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct PitchPreset {
+    pub name: String,
+    pub params: PitchParams,
+}
+
+/// Saved pitches the user can load back into [`SelectedPitchParameters`].
+#[derive(Debug, Resource)]
+pub(crate) struct PitchPresets(pub Vec<PitchPreset>);
+
+impl PitchPresets {
+    /// Adds the preset, replacing any preset with the same name.
+    pub(crate) fn save(&mut self, preset: PitchPreset) {
+        match self.0.iter_mut().find(|saved| saved.name == preset.name) {
+            Some(saved) => *saved = preset,
+            None => self.0.push(preset),
+        }
+    }
+}
+
+impl Default for PitchPresets {
+    fn default() -> Self {
+        let base = SelectedPitchParameters::default().0;
+        let preset =
+            |name: &str, speed: f32, spin_rate: f32, spin_efficiency: f32, tilt: (i8, i8)| {
+                PitchPreset {
+                    name: name.to_string(),
+                    params: PitchParams {
+                        speed,
+                        spin_rate,
+                        spin_efficiency,
+                        tilt: Tilt::from_hour_mintes(tilt.0, tilt.1)
+                            .expect("invalid preset tilt params".into()),
+                        ..base
+                    },
+                }
+            };
+        // typical right-handed arsenal
+        Self(vec![
+            preset("four-seam", 94., 2300., 0.95, (12, 45)),
+            preset("sinker", 92., 2150., 0.9, (1, 45)),
+            preset("changeup", 85., 1750., 0.9, (2, 0)),
+            preset("slider", 85., 2450., 0.35, (9, 0)),
+            preset("curveball", 79., 2600., 0.8, (7, 0)),
+        ])
+    }
 }
 
 #[derive(Debug, Reflect, Copy, Clone, Eq, PartialEq)]
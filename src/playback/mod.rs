@@ -0,0 +1,41 @@
+use crate::prelude::*;
+
+mod resources;
+mod systems;
+
+use systems::*;
+
+pub(crate) mod prelude {
+    pub(crate) use super::*;
+    pub(crate) use resources::*;
+}
+
+/// Slow motion, pause and single stepping.
+///
+/// Each physics step keeps its fixed length; slowing down only spaces the steps further
+/// apart in real time, so a pitch flies exactly the same path at any speed. Rapier and
+/// everything in [`PhysicsStepSet`] skip the frames in between together.
+///
+/// At most one step runs per frame, so playback is tied to the frame rate: below one frame
+/// per physics step, even 1x runs slower than real time. The playback window says so.
+#[derive(Debug)]
+pub(crate) struct PlaybackPlugin;
+
+/// Systems that advance with the physics: they only run on frames where Rapier steps,
+/// so nothing in the ball's flight moves, decays or gets sampled while paused.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub(crate) struct PhysicsStepSet;
+
+impl Plugin for PlaybackPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsPlayback>();
+
+        app.configure_sets(Update, PhysicsStepSet.run_if(physics_step_due))
+            .configure_sets(
+                Update,
+                UpdateBaseballFlightStateSet::PostUpdate.in_set(PhysicsStepSet),
+            );
+
+        app.add_systems(PreUpdate, advance_playback);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::prelude::*;
+
+/// Time scales offered by the slower and faster keys.
+pub(crate) const TIME_SCALES: [f32; 6] = [0.05, 0.1, 0.25, 0.5, 0.75, 1.];
+
+#[derive(Debug, Resource)]
+pub(crate) struct PhysicsPlayback {
+    /// speed relative to real time, (0, 1]
+    pub time_scale: f32,
+    pub paused: bool,
+    /// advance one step while paused
+    pub step_requested: bool,
+    /// real seconds, scaled, not yet spent on a step
+    pub accumulator: f32,
+    /// whether physics steps this frame
+    pub step_due: bool,
+    /// whether more than one step came due in a frame recently, so playback is running
+    /// slower than its time scale
+    pub falling_behind: bool,
+}
+
+impl Default for PhysicsPlayback {
+    fn default() -> Self {
+        Self {
+            time_scale: 1.,
+            paused: false,
+            step_requested: false,
+            accumulator: 0.,
+            step_due: true,
+            falling_behind: false,
+        }
+    }
+}
+
+impl PhysicsPlayback {
+    pub(crate) fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        self.accumulator = 0.;
+    }
+
+    /// Moves `step` places along [`TIME_SCALES`] from the current time scale.
+    pub(crate) fn shift_time_scale(&mut self, step: i32) {
+        let current = TIME_SCALES
+            .iter()
+            .position(|scale| *scale >= self.time_scale - f32::EPSILON)
+            .unwrap_or(TIME_SCALES.len() - 1) as i32;
+        let next = (current + step).clamp(0, TIME_SCALES.len() as i32 - 1) as usize;
+        self.time_scale = TIME_SCALES[next];
+    }
+
+    pub(crate) fn label(&self) -> String {
+        if self.paused {
+            "paused".to_string()
+        } else {
+            format!("{}x", self.time_scale)
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::prelude::*;
+
+/// Decides whether physics steps this frame, and holds Rapier back when it doesn't.
+/// Runs before anything in `Update`, so the flight systems and Rapier agree.
+pub(crate) fn advance_playback(
+    mut playback: ResMut<PhysicsPlayback>,
+    mut rapier_config: ResMut<RapierConfiguration>,
+    real_time: Res<Time<Real>>,
+    time: Res<Time>,
+) {
+    let dt = physics_timestep(&rapier_config, &time);
+    let mut falling_behind = false;
+    let step_due = if playback.paused {
+        std::mem::take(&mut playback.step_requested)
+    } else {
+        playback.accumulator += real_time.delta_seconds() * playback.time_scale;
+        if playback.accumulator >= dt {
+            // at most one step per frame; a slow frame doesn't pile up steps to catch up on
+            falling_behind = playback.accumulator >= 2. * dt;
+            playback.accumulator = (playback.accumulator - dt).min(dt);
+            true
+        } else {
+            false
+        }
+    };
+    if playback.falling_behind != falling_behind && !playback.paused {
+        playback.falling_behind = falling_behind;
+    }
+    if playback.step_due != step_due {
+        playback.step_due = step_due;
+    }
+    if rapier_config.physics_pipeline_active != step_due {
+        rapier_config.physics_pipeline_active = step_due;
+    }
+}
+
+/// Run condition for systems that advance with the physics.
+pub(crate) fn physics_step_due(playback: Res<PhysicsPlayback>) -> bool {
+    playback.step_due
+}
+
+pub(crate) fn pause_physics(mut playback: ResMut<PhysicsPlayback>) {
+    playback.toggle_pause();
+}
+
+pub(crate) fn step_physics(mut playback: ResMut<PhysicsPlayback>) {
+    if playback.paused {
+        playback.step_requested = true;
+    }
+}
+
+pub(crate) fn slow_down_physics(mut playback: ResMut<PhysicsPlayback>) {
+    playback.shift_time_scale(-1);
+}
+
+pub(crate) fn speed_up_physics(mut playback: ResMut<PhysicsPlayback>) {
+    playback.shift_time_scale(1);
+}
+
+pub(crate) fn playback_window(mut contexts: EguiContexts, mut playback: ResMut<PhysicsPlayback>) {
+    let ctx = contexts.ctx_mut();
+
+    egui::Window::new("playback")
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .button(if playback.paused { "resume" } else { "pause" })
+                    .clicked()
+                {
+                    playback.toggle_pause();
+                }
+                if ui
+                    .add_enabled(playback.paused, egui::Button::new("step"))
+                    .clicked()
+                {
+                    playback.step_requested = true;
+                }
+                ui.label(playback.label());
+            });
+            ui.horizontal(|ui| {
+                for scale in TIME_SCALES {
+                    let selected = (playback.time_scale - scale).abs() < f32::EPSILON;
+                    if ui
+                        .selectable_label(selected, format!("{}x", scale))
+                        .clicked()
+                        && !selected
+                    {
+                        playback.time_scale = scale;
+                    }
+                }
+            });
+            let mut time_scale = playback.time_scale;
+            if egui::Slider::new(&mut time_scale, TIME_SCALES[0]..=1.0_f32)
+                .text("speed")
+                .logarithmic(true)
+                .ui(ui)
+                .changed()
+            {
+                playback.time_scale = time_scale;
+            }
+            if playback.falling_behind {
+                ui.label(
+                    "the frame rate is below the physics rate, so playback is slower than shown",
+                );
+            }
+        });
+}
@@ -11,7 +11,7 @@ pub(crate) use bevy::{
     prelude::*,
     prelude::*,
     render::{
-        camera::{RenderTarget, ScalingMode},
+        camera::{RenderTarget, ScalingMode, Viewport},
         render_resource::{
             Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
         },
@@ -26,12 +26,22 @@ pub(crate) use bevy_rapier_baseball_flight::{
 };
 pub(crate) use bevy_third_person_camera::*;
 pub(crate) use blenvy::*;
+pub(crate) use egui_plot;
 
 pub(crate) use bevy_flycam::{FlyCam, NoCameraPlayerPlugin};
 
+pub(crate) use crate::aerodynamics::prelude::*;
+pub(crate) use crate::analysis::prelude::*;
 pub(crate) use crate::batter::prelude::*;
+pub(crate) use crate::cameras::prelude::*;
+pub(crate) use crate::controls::prelude::*;
+pub(crate) use crate::delivery::prelude::*;
 pub(crate) use crate::errors::*;
+pub(crate) use crate::field::prelude::*;
 pub(crate) use crate::materials::prelude::*;
 pub(crate) use crate::pitcher::prelude::*;
+pub(crate) use crate::playback::prelude::*;
 pub(crate) use crate::scenes::prelude::*;
+pub(crate) use crate::settings::prelude::*;
 pub(crate) use crate::strikezone::prelude::*;
+pub(crate) use crate::units::prelude::*;
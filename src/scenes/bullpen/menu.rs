@@ -5,6 +5,9 @@ use std::f32::consts::PI;
 pub(crate) enum MenuTab {
     #[default]
     Parameters,
+    Environment,
+    Presets,
+    Tools,
     Controls,
     Settings,
 }
@@ -13,7 +16,7 @@ pub(crate) enum MenuTab {
 pub(crate) struct MenuState {
     pub visibility: bool,
     pub selected_tab: MenuTab,
-    pub metric: bool,
+    pub preset_name: String,
 }
 
 pub(crate) fn menu_visibility_is(visibility: bool) -> impl FnMut(Res<MenuState>) -> bool + Clone {
@@ -40,6 +43,16 @@ pub(crate) fn params_menu(
     mut contexts: EguiContexts,
     mut selected_pitch_parameters: ResMut<SelectedPitchParameters>,
     mut active_batter_tracker: ResMut<ActiveBatterTracker>,
+    mut aero_environment: ResMut<AeroEnvironment>,
+    mut aero_profile: ResMut<AeroProfile>,
+    mut field_geometry: ResMut<FieldGeometry>,
+    mut aero_effects: ResMut<AeroEffects>,
+    mut ghost_trajectories: ResMut<GhostTrajectories>,
+    mut force_visualization: ResMut<ForceVisualization>,
+    mut pitch_presets: ResMut<PitchPresets>,
+    mut analysis_tools: AnalysisTools,
+    mut camera_controls: CameraControls,
+    mut preferences: Preferences,
     baseball_preview_image: Res<BaseballPreviewImage>,
     mut menu_state: ResMut<MenuState>,
     mut exit: EventWriter<AppExit>,
@@ -47,6 +60,7 @@ pub(crate) fn params_menu(
     let opt_cube_preview_texture_id = contexts.image_id(&baseball_preview_image);
 
     let ctx = contexts.ctx_mut();
+    let units = *preferences.units;
 
     egui::Window::new("menu").min_width(600.0).show(ctx, |ui| {
         ui.set_min_width(600.0);
@@ -60,6 +74,13 @@ pub(crate) fn params_menu(
                     MenuTab::Parameters,
                     "Parameters",
                 );
+                ui.selectable_value(
+                    &mut menu_state.selected_tab,
+                    MenuTab::Environment,
+                    "Environment",
+                );
+                ui.selectable_value(&mut menu_state.selected_tab, MenuTab::Presets, "Presets");
+                ui.selectable_value(&mut menu_state.selected_tab, MenuTab::Tools, "Tools");
                 ui.selectable_value(&mut menu_state.selected_tab, MenuTab::Controls, "Controls");
                 ui.selectable_value(&mut menu_state.selected_tab, MenuTab::Settings, "Settings");
             });
@@ -78,44 +99,19 @@ pub(crate) fn params_menu(
                                 egui::Grid::new("parameters").spacing([50.0, 50.0]).show(
                                     ui,
                                     |ui| {
-                                        // in cm
-                                        let mut batter_height = if menu_state.metric {
-                                            active_batter_tracker.height * 100.
-                                        } else {
-                                            active_batter_tracker.height * M_TO_FEET
-                                        };
-                                        ui.label(if menu_state.metric {
-                                            "batter height (cm)"
-                                        } else {
-                                            "batter height (ft)"
-                                        });
-                                        egui::Slider::new(
+                                        ui.label(unit_label("batter height", units.distance));
+                                        let mut batter_height = active_batter_tracker.height;
+                                        if unit_slider(
+                                            ui,
                                             &mut batter_height,
-                                            if menu_state.metric {
-                                                140.0_f32..=210.0_f32
-                                            } else {
-                                                4.6_f32..=7.0_f32
-                                            },
+                                            1.4_f32..=2.13_f32,
+                                            units.distance,
                                         )
-                                        .ui(ui);
-                                        ui.end_row();
-                                        if menu_state.metric {
-                                            if (batter_height / 100. - active_batter_tracker.height)
-                                                .abs()
-                                                >= 0.01
-                                            {
-                                                active_batter_tracker.height = batter_height / 100.;
-                                            }
-                                        } else {
-                                            if (batter_height / M_TO_FEET
-                                                - active_batter_tracker.height)
-                                                .abs()
-                                                >= 0.01
-                                            {
-                                                active_batter_tracker.height =
-                                                    batter_height / M_TO_FEET;
-                                            }
+                                        .changed()
+                                        {
+                                            active_batter_tracker.height = batter_height;
                                         }
+                                        ui.end_row();
 
                                         ui.label("Pitching Arm");
                                         ui.with_layout(
@@ -135,36 +131,63 @@ pub(crate) fn params_menu(
                                         );
                                         ui.end_row();
 
-                                        ui.label(if menu_state.metric {
-                                            "speed (km/h)"
-                                        } else {
-                                            "speed (mph)"
-                                        });
-                                        if menu_state.metric {
-                                            let mut selected_speed =
-                                                selected_pitch_parameters.0.speed / KMH_TO_MPH;
-                                            egui::Slider::new(
-                                                &mut selected_speed,
-                                                50.0_f32..=180.0_f32,
-                                            )
-                                            .ui(ui);
-                                            selected_pitch_parameters.0.speed =
-                                                selected_speed * KMH_TO_MPH;
-                                        } else {
-                                            egui::Slider::new(
-                                                &mut selected_pitch_parameters.0.speed,
-                                                30.0_f32..=110.0_f32,
-                                            )
-                                            .ui(ui);
+                                        ui.label(unit_label("speed", units.speed));
+                                        // pitch speeds are kept in mph
+                                        let mut speed = selected_pitch_parameters.0.speed * MPH_TO_MPS;
+                                        if unit_slider(
+                                            ui,
+                                            &mut speed,
+                                            30. * MPH_TO_MPS..=110. * MPH_TO_MPS,
+                                            units.speed,
+                                        )
+                                        .changed()
+                                        {
+                                            selected_pitch_parameters.0.speed = speed / MPH_TO_MPS;
                                         }
                                         ui.end_row();
 
-                                        ui.label("spin (rpm)");
-                                        egui::Slider::new(
-                                            &mut selected_pitch_parameters.0.spin_rate,
-                                            500.0_f32..=3000.0_f32,
+                                        ui.label("knuckleball");
+                                        ui.horizontal(|ui| {
+                                            let pitch = &mut selected_pitch_parameters.0;
+                                            let mut knuckleball = pitch.knuckleball_seed.is_some();
+                                            if ui.checkbox(&mut knuckleball, "").changed() {
+                                                if knuckleball {
+                                                    pitch.knuckleball_seed = Some(new_knuckleball_seed(ui));
+                                                    pitch.spin_rate = pitch.spin_rate.min(60.);
+                                                } else {
+                                                    pitch.knuckleball_seed = None;
+                                                    pitch.spin_rate = pitch.spin_rate.max(500.);
+                                                }
+                                            }
+                                            if let Some(seed) = pitch.knuckleball_seed.as_mut() {
+                                                ui.label("seed");
+                                                ui.add(egui::DragValue::new(seed));
+                                                if ui.button("New").clicked() {
+                                                    *seed = new_knuckleball_seed(ui);
+                                                }
+                                            }
+                                        });
+                                        ui.end_row();
+
+                                        ui.label(unit_label("spin", units.spin));
+                                        // spin rates are kept in rpm
+                                        let (lowest, highest) =
+                                            if selected_pitch_parameters.0.knuckleball_seed.is_some() {
+                                                (0., 300.)
+                                            } else {
+                                                (500., 3000.)
+                                            };
+                                        let mut spin = selected_pitch_parameters.0.spin_rate / RADS_TO_RPM;
+                                        if unit_slider(
+                                            ui,
+                                            &mut spin,
+                                            lowest / RADS_TO_RPM..=highest / RADS_TO_RPM,
+                                            units.spin,
                                         )
-                                        .ui(ui);
+                                        .changed()
+                                        {
+                                            selected_pitch_parameters.0.spin_rate = spin * RADS_TO_RPM;
+                                        }
                                         ui.end_row();
 
                                         ui.label("spin efficiency (%)");
@@ -321,16 +344,62 @@ pub(crate) fn params_menu(
                             });
                         });
                     }
+                    MenuTab::Environment => {
+                        ui.add_space(20.0);
+                        // edited on copies so the resources only change when a value does
+                        let mut field = *field_geometry;
+                        let mut profile = *aero_profile;
+                        field_section(ui, &mut field, &mut profile, units);
+                        field_geometry.set_if_neq(field);
+                        ui.add_space(20.0);
+                        let mut environment = *aero_environment;
+                        environment_tab(ui, &mut environment, units);
+                        aero_environment.set_if_neq(environment);
+                        ui.add_space(20.0);
+                        ball_section(ui, &mut profile, units);
+                        aero_profile.set_if_neq(profile);
+                        ui.add_space(20.0);
+                        let mut effects = *aero_effects;
+                        effects_section(
+                            ui,
+                            &mut effects,
+                            &mut ghost_trajectories,
+                            &mut force_visualization,
+                            units,
+                        );
+                        aero_effects.set_if_neq(effects);
+                    }
+                    MenuTab::Presets => {
+                        ui.add_space(20.0);
+                        presets_tab(
+                            ui,
+                            &mut pitch_presets,
+                            &mut selected_pitch_parameters,
+                            &mut menu_state.preset_name,
+                        );
+                    }
+                    MenuTab::Tools => {
+                        ui.add_space(20.0);
+                        ui.vertical(|ui| analysis_tools.toggles(ui));
+                    }
                     MenuTab::Controls => {
                         ui.add_space(20.0);
                         ui.vertical(|ui| {
                             ui.heading("Controls");
                             ui.add_space(10.0);
 
-                            ui.label("• Aim with mouse");
-                            ui.label("• Right Mouse Button to aim, then release to launch");
-                            ui.label("• Press R to reset ball");
+                            ui.label("• Aim with mouse or the right stick");
+                            ui.label("• Hold aim and throw to preview the pitch, then release to launch");
+                            ui.label("• With the timing meter delivery, let go as the needle reaches the green mark for full effort; early sails high, late drops low");
+                            ui.label("• On the ball camera, hold orbit and drag, or use the right stick, to orbit the last pitch, and scroll to zoom");
                             ui.label("• Use mouse wheel to zoom in and out");
+                            ui.add_space(10.0);
+
+                            egui::Grid::new("bindings")
+                                .spacing([20.0, 8.0])
+                                .show(ui, |ui| {
+                                    preferences.bindings.settings(ui);
+                                });
                         });
                     }
                     MenuTab::Settings => {
@@ -338,13 +407,8 @@ pub(crate) fn params_menu(
                         egui::Grid::new("parameters")
                             .spacing([20.0, 20.0])
                             .show(ui, |ui| {
-                                if ui
-                                    .add(egui::RadioButton::new(menu_state.metric, "metric"))
-                                    .clicked()
-                                {
-                                    menu_state.metric = !menu_state.metric;
-                                };
-                                ui.end_row();
+                                camera_controls.settings(ui);
+                                preferences.settings(ui);
                                 //
                                 if ui.button("Exit Game").clicked() {
                                     exit.send(AppExit::Success);
@@ -358,6 +422,306 @@ pub(crate) fn params_menu(
     });
 }
 
+/// Seed for a new knuckleball, different every time it's called.
+/// Nanoseconds since the game started, from egui, since wasm has no system clock.
+fn new_knuckleball_seed(ui: &egui::Ui) -> u64 {
+    (ui.input(|input| input.time) * 1e9) as u64
+}
+
+fn environment_tab(ui: &mut egui::Ui, environment: &mut AeroEnvironment, units: Units) {
+    ui.horizontal(|ui| {
+        for preset in EnvironmentPreset::ALL {
+            if ui.button(preset.label()).clicked() {
+                *environment = preset.environment();
+            }
+        }
+    });
+    ui.add_space(10.0);
+
+    egui::Grid::new("environment")
+        .spacing([50.0, 20.0])
+        .show(ui, |ui| {
+            ui.label(unit_label("altitude", units.distance));
+            unit_slider(
+                ui,
+                &mut environment.altitude,
+                -100.0_f32..=3050.0_f32,
+                units.distance,
+            );
+            ui.end_row();
+
+            ui.label(unit_label("temperature", units.temperature));
+            unit_slider(
+                ui,
+                &mut environment.temperature,
+                -10.0_f32..=45.0_f32,
+                units.temperature,
+            );
+            ui.end_row();
+
+            ui.label(unit_label("pressure", units.pressure));
+            unit_slider(
+                ui,
+                &mut environment.pressure,
+                950.0_f32..=1060.0_f32,
+                units.pressure,
+            );
+            ui.end_row();
+
+            ui.label("humidity (%)");
+            let mut humidity = environment.humidity * 100.;
+            egui::Slider::new(&mut humidity, 0.0_f32..=100.0_f32).ui(ui);
+            environment.humidity = humidity / 100.;
+            ui.end_row();
+
+            // 0° blows from the mound toward home plate
+            let wind_xz = Vec2::new(environment.wind.x, -environment.wind.z);
+            let mut wind_speed = wind_xz.length();
+            let mut wind_direction = wind_xz.x.atan2(wind_xz.y).to_degrees();
+            if wind_direction < 0. {
+                wind_direction += 360.;
+            }
+            ui.label(unit_label("wind", units.speed));
+            unit_slider(ui, &mut wind_speed, 0.0..=30. * MPH_TO_MPS, units.speed);
+            ui.end_row();
+
+            ui.label("wind direction (°)");
+            egui::Slider::new(&mut wind_direction, 0.0_f32..=360.0_f32).ui(ui);
+            ui.end_row();
+
+            let wind_direction = wind_direction.to_radians();
+            let wind = Vec3::new(wind_direction.sin(), 0., -wind_direction.cos()) * wind_speed;
+            if wind.distance(environment.wind) >= 0.001 {
+                environment.wind = Vec3::new(wind.x, environment.wind.y, wind.z);
+            }
+
+            ui.label("air density (kg/m³)");
+            ui.label(format!("{:.3}", environment.air_density()));
+            ui.end_row();
+        });
+}
+
+/// Takes the presets as `ResMut` so they're only marked changed when edited.
+fn presets_tab(
+    ui: &mut egui::Ui,
+    pitch_presets: &mut ResMut<PitchPresets>,
+    selected_pitch_parameters: &mut SelectedPitchParameters,
+    preset_name: &mut String,
+) {
+    let mut deleted = None;
+    egui::Grid::new("presets")
+        .spacing([20.0, 10.0])
+        .show(ui, |ui| {
+            for (i, preset) in pitch_presets.0.iter().enumerate() {
+                ui.label(&preset.name);
+                if ui.button("Load").clicked() {
+                    // presets don't change where the pitcher is aiming
+                    selected_pitch_parameters.0 = PitchParams {
+                        direction: selected_pitch_parameters.0.direction,
+                        ..preset.params
+                    };
+                }
+                if ui.button("Delete").clicked() {
+                    deleted = Some(i);
+                }
+                ui.end_row();
+            }
+        });
+    if let Some(i) = deleted {
+        pitch_presets.0.remove(i);
+    }
+
+    ui.add_space(10.0);
+    ui.horizontal(|ui| {
+        ui.text_edit_singleline(preset_name);
+        if ui
+            .add_enabled(!preset_name.is_empty(), egui::Button::new("Save current"))
+            .clicked()
+        {
+            pitch_presets.save(PitchPreset {
+                name: preset_name.clone(),
+                params: selected_pitch_parameters.0,
+            });
+        }
+    });
+}
+
+fn field_section(
+    ui: &mut egui::Ui,
+    field: &mut FieldGeometry,
+    profile: &mut AeroProfile,
+    units: Units,
+) {
+    ui.label("Field");
+    ui.horizontal_wrapped(|ui| {
+        for preset in FieldPreset::ALL {
+            if ui.button(preset.label()).clicked() {
+                *field = preset.geometry();
+                *profile = AeroProfile {
+                    drag_model: profile.drag_model,
+                    lift_model: profile.lift_model,
+                    ..preset.ball().profile()
+                };
+            }
+        }
+    });
+    ui.add_space(10.0);
+
+    egui::Grid::new("field")
+        .spacing([50.0, 20.0])
+        .show(ui, |ui| {
+            ui.label(unit_label("pitching distance", units.distance));
+            unit_slider(
+                ui,
+                &mut field.pitching_distance,
+                10.0_f32..=19.0_f32,
+                units.distance,
+            );
+            ui.end_row();
+
+            ui.label(unit_label("mound height", units.length));
+            unit_slider(
+                ui,
+                &mut field.mound_height,
+                0.0_f32..=0.305_f32,
+                units.length,
+            );
+            ui.end_row();
+
+            ui.label("strike zone (% of batter height)");
+            ui.label(format!(
+                "{:.0}-{:.0}",
+                field.strikezone.bottom * 100.,
+                field.strikezone.top * 100.
+            ));
+            ui.end_row();
+        });
+}
+
+fn ball_section(ui: &mut egui::Ui, profile: &mut AeroProfile, units: Units) {
+    ui.label("Ball");
+    ui.horizontal(|ui| {
+        for preset in BallPreset::ALL {
+            if ui.button(preset.label()).clicked() {
+                // keep the coefficient models, only swap the ball
+                *profile = AeroProfile {
+                    drag_model: profile.drag_model,
+                    lift_model: profile.lift_model,
+                    ..preset.profile()
+                };
+            }
+        }
+    });
+    ui.add_space(10.0);
+
+    egui::Grid::new("ball")
+        .spacing([50.0, 20.0])
+        .show(ui, |ui| {
+            ui.label("drag model");
+            egui::ComboBox::from_id_salt("drag model")
+                .selected_text(profile.drag_model.label())
+                .show_ui(ui, |ui| {
+                    for model in DragModel::ALL {
+                        ui.selectable_value(&mut profile.drag_model, model, model.label());
+                    }
+                });
+            ui.end_row();
+
+            ui.label("lift model");
+            egui::ComboBox::from_id_salt("lift model")
+                .selected_text(profile.lift_model.label())
+                .show_ui(ui, |ui| {
+                    for model in LiftModel::ALL {
+                        ui.selectable_value(&mut profile.lift_model, model, model.label());
+                    }
+                });
+            ui.end_row();
+
+            ui.label(unit_label("mass", units.mass));
+            unit_slider(ui, &mut profile.mass, 0.135_f32..=0.2_f32, units.mass);
+            ui.end_row();
+
+            ui.label(unit_label("circumference", units.length));
+            unit_slider(
+                ui,
+                &mut profile.circumference,
+                0.22_f32..=0.311_f32,
+                units.length,
+            );
+            ui.end_row();
+
+            // too small for the slider's default precision
+            ui.label(unit_label("seam height", units.length));
+            let mut seam_height = units.length.from_base(profile.seam_height);
+            if egui::Slider::new(&mut seam_height, 0.0..=units.length.from_base(0.0015))
+                .fixed_decimals(3)
+                .ui(ui)
+                .changed()
+            {
+                profile.seam_height = units.length.to_base(seam_height);
+            }
+            ui.end_row();
+        });
+}
+
+fn effects_section(
+    ui: &mut egui::Ui,
+    effects: &mut AeroEffects,
+    ghost_trajectories: &mut GhostTrajectories,
+    force_visualization: &mut ForceVisualization,
+    units: Units,
+) {
+    ui.label("Effects");
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut effects.drag_on, "drag");
+        ui.checkbox(&mut effects.magnus_on, "Magnus");
+        ui.checkbox(&mut effects.ssw_on, "seam-shifted wake");
+    });
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut effects.spin_decay_on, "spin decay");
+        ui.checkbox(&mut effects.precession_on, "gyro precession");
+    });
+    ui.checkbox(
+        &mut ghost_trajectories.enabled,
+        "ghost mode: draw the last pitch without each effect",
+    );
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut force_visualization.arrows, "force arrows");
+        ui.checkbox(&mut force_visualization.plot, "force plot");
+        ui.label("arrow scale (m/N)");
+        egui::Slider::new(&mut force_visualization.scale, 0.01_f32..=1.0_f32).ui(ui);
+    });
+
+    if !ghost_trajectories.enabled {
+        return;
+    }
+    let Some(as_thrown) = ghost_trajectories
+        .paths
+        .first()
+        .and_then(|ghost| ghost.trajectory.plate_location())
+    else {
+        return;
+    };
+    egui::Grid::new("ghosts")
+        .spacing([20.0, 5.0])
+        .show(ui, |ui| {
+            ui.label("");
+            ui.label(unit_label("horizontal", units.length));
+            ui.label(unit_label("vertical", units.length));
+            ui.end_row();
+            for ghost in ghost_trajectories.paths.iter().skip(1) {
+                if let Some(plate_location) = ghost.trajectory.plate_location() {
+                    // movement the missing effect is responsible for
+                    let difference = (as_thrown - plate_location) * units.length.from_base(1.);
+                    ui.colored_label(egui_color(ghost.color), ghost.label);
+                    ui.label(format!("{:+.1}", difference.x));
+                    ui.label(format!("{:+.1}", difference.y));
+                    ui.end_row();
+                }
+            }
+        });
+}
+
 pub(crate) fn update_baseball_preview_3d(
     selected_pitch_parameters: Res<SelectedPitchParameters>,
     mut query_baseball_preview: Query<
@@ -393,6 +757,7 @@ pub(crate) fn update_baseball_preview_3d(
             tilt,
             direction: _,
             seam_y_angle: _,
+            knuckleball_seed: _,
         } = selected_pitch_parameters.0;
         let spin_axis =
             get_angular_velocity_from_parameters(tilt, spin_efficiency, spin_rate, gyro_pole)
@@ -15,9 +15,20 @@ pub(crate) mod prelude {
 }
 
 // bullpen scene
-#[derive(Debug, Reflect, States, Hash, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Reflect, Hash, Eq, PartialEq, Clone, Copy)]
 pub(crate) struct BullpenScene;
 
+impl ComputedStates for BullpenScene {
+    type SourceStates = SelectedScene;
+
+    fn compute(sources: SelectedScene) -> Option<Self> {
+        match sources {
+            SelectedScene::Bullpen => Some(BullpenScene),
+            _ => None,
+        }
+    }
+}
+
 impl GameScene for BullpenScene {
     fn configure_set(&self, app: &mut App) {
         app.configure_sets(
@@ -28,10 +39,8 @@ impl GameScene for BullpenScene {
             Update,
             GameScenesSet::UpdateSet(*self).run_if(in_state(*self)),
         )
-        .configure_sets(
-            OnExit(*self),
-            GameScenesSet::OnExitSet(*self).run_if(in_state(*self)),
-        );
+        // the scene state is already gone by the time OnExit runs
+        .configure_sets(OnExit(*self), GameScenesSet::OnExitSet(*self));
     }
 
     fn register_type(&self, app: &mut App) {
@@ -52,7 +61,8 @@ impl Plugin for BullpenScene {
         self.add_events(app);
         self.configure_set(app);
 
-        app.init_state::<BullpenSceneGameMode>();
+        app.add_computed_state::<Self>();
+        app.add_sub_state::<BullpenSceneGameMode>();
 
         app.add_plugins(PitcherPlugin::<BullpenScene> {
             scene: *self,
@@ -62,14 +72,23 @@ impl Plugin for BullpenScene {
             scene: *self,
             render_layers: vec![0],
         })
-        .add_plugins(StrikezonePlugin::<BullpenScene> { scene: *self });
+        .add_plugins(StrikezonePlugin::<BullpenScene> { scene: *self })
+        .add_plugins(FieldPlugin::<BullpenScene> { scene: *self })
+        .add_plugins(CamerasPlugin::<BullpenScene> {
+            scene: *self,
+            render_layers: vec![0],
+        })
+        .add_plugins(AnalysisPlugin::<BullpenScene> { scene: *self });
 
-        app.insert_resource(MenuState::default());
-        app.insert_resource(ActiveBatterTracker { height: 1.8 });
+        app.init_resource::<MenuState>();
+        app.init_resource::<ActiveBatterTracker>();
 
         app.add_systems(
             OnEnter(Self),
             (
+                reset_resource::<MenuState>,
+                reset_resource::<ActiveBatterTracker>,
+                reset_resource::<Delivery>,
                 setup_scene,
                 setup_baseball_preview_scene, //
                                               // _spawn_camera.after(setup_scene),
@@ -77,14 +96,44 @@ impl Plugin for BullpenScene {
                 .chain()
                 .in_set(GameScenesSet::OnEnterSet(*self)),
         )
+        .add_systems(
+            OnEnter(Self),
+            apply_user_settings
+                .after(reset_resource::<CameraSelector>)
+                .in_set(GameScenesSet::OnEnterSet(*self)),
+        )
+        .add_systems(
+            OnExit(Self),
+            (
+                despawn_scene_entities::<Self>,
+                teardown_baseball_preview_scene, //
+            )
+                .in_set(GameScenesSet::OnExitSet(*self)),
+        )
         // resource trackers
         .add_systems(
             Update,
             (
-                active_batter_changed, //
+                active_batter_changed,
+                follow_active_camera,
+                record_user_settings,
             )
                 .in_set(GameScenesSet::UpdateSet(*self)),
         )
+        .add_systems(
+            Update,
+            (aim_with_gamepad, apply_cursor_lock)
+                .after(follow_active_camera)
+                .run_if(in_state(SceneState::Loaded))
+                .in_set(GameScenesSet::UpdateSet(*self)),
+        )
+        .add_systems(
+            Update,
+            check_scene_loaded
+                .run_if(in_state(SceneState::Loading))
+                .in_set(GameScenesSet::UpdateSet(*self))
+                .in_set(GltfBlueprintsSet::AfterSpawn),
+        )
         .add_systems(
             Update,
             toggle_menu_visibility.in_set(GameScenesSet::UpdateSet(*self)),
@@ -99,12 +148,14 @@ impl Plugin for BullpenScene {
                 )
                     .run_if(menu_visibility_is(true)),
                 (
+                    toggle_cursor_lock,
                     third_person_camera_lock_status, //
                 )
                     .chain()
-                    .run_if(input_just_pressed(KeyCode::Escape)),
+                    .run_if(action_just_pressed(GameAction::ToggleMenu)),
             )
                 .chain()
+                .run_if(in_state(SceneState::Loaded))
                 .in_set(GameScenesSet::UpdateSet(*self)),
         )
         .add_systems(
@@ -116,21 +167,40 @@ impl Plugin for BullpenScene {
         .add_systems(
             Update,
             (spawn_ball
-                .run_if(input_just_pressed(MouseButton::Right))
+                .run_if(action_just_pressed(GameAction::Throw))
+                .run_if(in_state(SceneState::Loaded))
                 .in_set(AeroActivationSet::PreActivation))
             .in_set(GameScenesSet::UpdateSet(*self)),
         )
+        .add_systems(
+            Update,
+            (
+                start_delivery.run_if(action_just_pressed(GameAction::Throw)),
+                delivery_meter,
+            )
+                .run_if(in_state(SceneState::Loaded))
+                .in_set(GameScenesSet::UpdateSet(*self)),
+        )
+        .add_systems(
+            Update,
+            (draw_predicted_pitch
+                .run_if(action_pressed(GameAction::Throw))
+                .run_if(in_state(SceneState::Loaded)))
+            .in_set(GameScenesSet::UpdateSet(*self)),
+        )
         .add_systems(
             Update,
             (launch_ball
-                .run_if(input_just_released(MouseButton::Right))
+                .run_if(action_just_released(GameAction::Throw))
+                .run_if(in_state(SceneState::Loaded))
                 .in_set(AeroActivationSet::PreActivation))
             .in_set(GameScenesSet::UpdateSet(*self)),
         )
         .add_systems(
             Update,
             (despawn_ball
-                .run_if(input_just_released(KeyCode::KeyR))
+                .run_if(action_just_released(GameAction::ResetBall))
+                .run_if(in_state(SceneState::Loaded))
                 .in_set(AeroActivationSet::PostActivation))
             .in_set(GameScenesSet::UpdateSet(*self)),
         )
@@ -142,7 +212,22 @@ impl Plugin for BullpenScene {
         )
         .add_systems(
             Update,
-            (swap_camera.run_if(input_just_pressed(KeyCode::KeyQ)))
+            (cycle_camera
+                .run_if(action_just_pressed(GameAction::CycleCamera))
+                .run_if(in_state(SceneState::Loaded)))
+            .in_set(GameScenesSet::UpdateSet(*self)),
+        )
+        // playback
+        .add_systems(
+            Update,
+            (
+                pause_physics.run_if(action_just_pressed(GameAction::Pause)),
+                step_physics.run_if(action_just_pressed(GameAction::Step)),
+                slow_down_physics.run_if(action_just_pressed(GameAction::SlowDown)),
+                speed_up_physics.run_if(action_just_pressed(GameAction::SpeedUp)),
+                playback_window.run_if(menu_visibility_is(true)),
+            )
+                .run_if(in_state(SceneState::Loaded))
                 .in_set(GameScenesSet::UpdateSet(*self)),
         );
     }
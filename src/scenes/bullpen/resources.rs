@@ -9,24 +9,35 @@ impl BaseballPreviewImage {
     }
 }
 
-#[derive(Debug, Resource, Default)]
+#[derive(Debug, Resource)]
 pub(crate) struct ActiveBatterTracker {
     /// batter's height in meters
     pub height: f32,
 }
 
+impl Default for ActiveBatterTracker {
+    fn default() -> Self {
+        Self { height: 1.8 }
+    }
+}
+
 pub(crate) fn active_batter_changed(
     active_batter_tracker: Res<ActiveBatterTracker>,
+    field: Res<FieldGeometry>,
     mut ev_redraw_strikezone: EventWriter<RedrawStrikezone>,
 ) {
-    if active_batter_tracker.is_changed() {
+    if active_batter_tracker.is_changed() || field.is_changed() {
         ev_redraw_strikezone.send(RedrawStrikezone {
             batter_height: active_batter_tracker.height,
+            rule: field.strikezone,
         });
     }
 }
 
-#[derive(Debug, Default, States, Hash, Eq, PartialEq, Clone, Copy)]
+/// Only exists while the bullpen is the selected scene,
+/// so it starts over as `Pitcher` every time the scene is entered.
+#[derive(Debug, Default, SubStates, Hash, Eq, PartialEq, Clone, Copy)]
+#[source(SelectedScene = SelectedScene::Bullpen)]
 pub(crate) enum BullpenSceneGameMode {
     #[default]
     Pitcher,
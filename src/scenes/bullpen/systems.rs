@@ -3,6 +3,9 @@ use bevy_rapier3d::rapier::prelude::CollisionEventFlags;
 use super::resources::BaseballPreviewImage;
 use crate::prelude::*;
 
+/// radians per second at full stick
+const GAMEPAD_AIM_SPEED: f32 = 0.6;
+
 pub(crate) fn _spawn_camera(mut commands: Commands) {
     commands.spawn((
         Name::new("fly cam"),
@@ -19,49 +22,37 @@ pub(crate) fn _spawn_camera(mut commands: Commands) {
     ));
 }
 
-pub(crate) fn swap_camera(
+/// Aiming and the pitcher game mode only apply while looking through the pitcher camera.
+/// Every other camera is spectating, which counts as the batter's side.
+pub(crate) fn follow_active_camera(
+    camera_selector: Res<CameraSelector>,
     game_mode: Res<State<BullpenSceneGameMode>>,
     mut next_game_mode: ResMut<NextState<BullpenSceneGameMode>>,
-    mut pitcher_camera_camera: Query<(Entity, &mut ThirdPersonCamera), With<PitcherCameraMarker>>,
-    batter_camera_camera: Query<Entity, With<BatterCameraMarker>>,
-    mut camera_query: Query<
-        &mut Camera,
-        Or<(
-            // With<FlyCamMarker>,
-            With<PitcherCameraMarker>,
-            With<BatterCameraMarker>,
-        )>,
-    >,
+    mut query_pitcher_camera: Query<(Entity, &mut ThirdPersonCamera), With<PitcherCameraMarker>>,
 ) {
-    info!("swap camera {:?}", game_mode.get());
-    let (pitcher_camera_entity, mut pitcher_aim) = pitcher_camera_camera.single_mut();
-    let batter_camera_entity = batter_camera_camera.single();
-    let [mut pitcher_camera, mut batter_camera] = camera_query
-        .get_many_mut([pitcher_camera_entity, batter_camera_entity])
-        .unwrap();
-    info!("pitcher camera {:?}", pitcher_camera.is_active);
-    info!("batter camera {:?}", batter_camera.is_active);
-    info!(
-        "pitcher aim {:?} {:?}",
-        pitcher_aim.cursor_lock_active, pitcher_aim.cursor_lock_toggle_enabled
-    );
-    match game_mode.get() {
-        BullpenSceneGameMode::Batter => {
-            pitcher_aim.cursor_lock_active = true;
-            // pitcher_aim.cursor_lock_toggle_enabled = false;
-            pitcher_camera.is_active = true;
-            batter_camera.is_active = false;
-            next_game_mode.set(BullpenSceneGameMode::Pitcher);
-        }
-        BullpenSceneGameMode::Pitcher => {
-            pitcher_aim.cursor_lock_active = false;
-            // pitcher_aim.cursor_lock_toggle_enabled = true;
-            pitcher_camera.is_active = false;
-            batter_camera.is_active = true;
-            next_game_mode.set(BullpenSceneGameMode::Batter);
-        }
+    if !camera_selector.is_changed() {
+        return;
+    }
+    let Ok((pitcher_camera, mut pitcher_aim)) = query_pitcher_camera.get_single_mut() else {
+        return;
+    };
+    let pitching = camera_selector.active == Some(pitcher_camera);
+    if pitcher_aim.cursor_lock_active != pitching {
+        pitcher_aim.cursor_lock_active = pitching;
+    }
+    let mode = if pitching {
+        BullpenSceneGameMode::Pitcher
+    } else {
+        BullpenSceneGameMode::Batter
+    };
+    if *game_mode.get() != mode {
+        info!(
+            "camera {}, game mode {:?}",
+            camera_selector.active_label(),
+            mode
+        );
+        next_game_mode.set(mode);
     }
-    info!("camera swapped {:?}", game_mode.get());
 }
 
 #[derive(Debug, Component, Reflect)]
@@ -71,6 +62,7 @@ pub(crate) struct StrikezoneSpawnRequestMarker;
 pub(crate) fn spawn_strikezone(
     mut commands: Commands,
     active_batter_tracker: Res<ActiveBatterTracker>,
+    field: Res<FieldGeometry>,
     query_strikezone_spawn_request_marker: Query<Entity, With<StrikezoneSpawnRequestMarker>>,
     mut ev_spawn: EventWriter<SpawnStrikezone>,
 ) {
@@ -81,10 +73,98 @@ pub(crate) fn spawn_strikezone(
             .remove::<StrikezoneSpawnRequestMarker>();
         ev_spawn.send(SpawnStrikezone {
             batter_height: active_batter_tracker.height,
+            rule: field.strikezone,
         });
     }
 }
 
+/// Brings back the camera layout and visual options from the last session.
+pub(crate) fn apply_user_settings(
+    settings: Res<UserSettings>,
+    mut camera_selector: ResMut<CameraSelector>,
+    mut ghost_trajectories: ResMut<GhostTrajectories>,
+    mut force_visualization: ResMut<ForceVisualization>,
+) {
+    camera_selector.layout = settings.layout;
+    ghost_trajectories.enabled = settings.ghost_trajectories;
+    force_visualization.arrows = settings.force_arrows;
+    force_visualization.plot = settings.force_plot;
+}
+
+/// Keeps the settings in step with the options changed around the menu.
+pub(crate) fn record_user_settings(
+    mut settings: ResMut<UserSettings>,
+    camera_selector: Res<CameraSelector>,
+    ghost_trajectories: Res<GhostTrajectories>,
+    force_visualization: Res<ForceVisualization>,
+) {
+    settings.set_if_neq(UserSettings {
+        layout: camera_selector.layout,
+        ghost_trajectories: ghost_trajectories.enabled,
+        force_arrows: force_visualization.arrows,
+        force_plot: force_visualization.plot,
+        ..settings.clone()
+    });
+}
+
+/// The camera plugin's own cursor toggle is off, so the menu goes through the action map.
+pub(crate) fn toggle_cursor_lock(
+    mut query_pitcher_camera: Query<&mut ThirdPersonCamera, With<PitcherCameraMarker>>,
+) {
+    for mut pitcher_aim in query_pitcher_camera.iter_mut() {
+        pitcher_aim.cursor_lock_active = !pitcher_aim.cursor_lock_active;
+    }
+}
+
+pub(crate) fn apply_cursor_lock(
+    query_pitcher_camera: Query<
+        &ThirdPersonCamera,
+        (With<PitcherCameraMarker>, Changed<ThirdPersonCamera>),
+    >,
+    mut primary_window: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(pitcher_aim) = query_pitcher_camera.get_single() else {
+        return;
+    };
+    let Ok(mut window) = primary_window.get_single_mut() else {
+        return;
+    };
+    let (grab_mode, visible) = if pitcher_aim.cursor_lock_active {
+        (CursorGrabMode::Locked, false)
+    } else {
+        (CursorGrabMode::None, true)
+    };
+    if window.cursor.grab_mode != grab_mode || window.cursor.visible != visible {
+        window.cursor.grab_mode = grab_mode;
+        window.cursor.visible = visible;
+    }
+}
+
+/// Turns the pitcher camera around the pitcher with the right stick, like the mouse does.
+pub(crate) fn aim_with_gamepad(
+    time: Res<Time<Real>>,
+    action_state: Res<ActionState<GameAction>>,
+    mut query_pitcher_camera: Query<
+        (&ThirdPersonCamera, &mut Transform),
+        With<PitcherCameraMarker>,
+    >,
+) {
+    let look = action_state.axis_pair(&GameAction::Look);
+    if look == Vec2::ZERO {
+        return;
+    }
+    let Ok((pitcher_aim, mut transform)) = query_pitcher_camera.get_single_mut() else {
+        return;
+    };
+    if !pitcher_aim.cursor_lock_active {
+        return;
+    }
+    let angle = look * GAMEPAD_AIM_SPEED * time.delta_seconds();
+    let yaw = Quat::from_rotation_y(-angle.x);
+    let pitch = Quat::from_rotation_x(angle.y);
+    transform.rotation = yaw * transform.rotation * pitch;
+}
+
 pub(crate) fn third_person_camera_lock_status(
     query_third_person_camera: Query<&ThirdPersonCamera, With<PitcherCameraMarker>>,
 ) {
@@ -149,6 +229,7 @@ pub(crate) fn setup_baseball_preview_scene(
             ..default()
         },
         render_layer.clone(),
+        GameSceneMarker(BullpenScene),
     ));
 
     // spawn preview pass camera
@@ -171,9 +252,21 @@ pub(crate) fn setup_baseball_preview_scene(
             ..default()
         },
         render_layer.clone(),
+        GameSceneMarker(BullpenScene),
     ));
 }
 
+pub(crate) fn teardown_baseball_preview_scene(
+    mut commands: Commands,
+    mut egui_user_textures: ResMut<EguiUserTextures>,
+    baseball_preview_image: Option<Res<BaseballPreviewImage>>,
+) {
+    if let Some(baseball_preview_image) = baseball_preview_image {
+        egui_user_textures.remove_image(&baseball_preview_image);
+    }
+    commands.remove_resource::<BaseballPreviewImage>();
+}
+
 pub(crate) fn setup_scene(mut commands: Commands) {
     // TODO: need to add render layers to blenvy
     commands.spawn((
@@ -181,10 +274,31 @@ pub(crate) fn setup_scene(mut commands: Commands) {
         SpawnBlueprint,
         HideUntilReady,
         GameWorldTag,
+        GameSceneMarker(BullpenScene),
         RenderLayers::from_layers(&[0]),
     ));
 }
 
+/// The bullpen is loaded once the level blueprint is ready
+/// and the pitcher camera and strikezone have been attached to it.
+/// Only the bullpen's level root is checked: the cameras, lights and balls that
+/// share `GameSceneMarker` for teardown aren't blueprints and never become ready.
+pub(crate) fn check_scene_loaded(
+    query_level: Query<
+        Has<BlueprintInstanceReady>,
+        (With<GameWorldTag>, With<GameSceneMarker<BullpenScene>>),
+    >,
+    query_pitcher_camera: Query<(), With<PitcherCameraMarker>>,
+    query_strikezone_panel: Query<(), With<StrikezonePanel>>,
+    mut next_scene_state: ResMut<NextState<SceneState>>,
+) {
+    let level_ready = !query_level.is_empty() && query_level.iter().all(|ready| ready);
+    if level_ready && !query_pitcher_camera.is_empty() && !query_strikezone_panel.is_empty() {
+        info!("bullpen scene loaded");
+        next_scene_state.set(SceneState::Loaded);
+    }
+}
+
 #[derive(Debug, Component)]
 pub(crate) struct BaseballMarker;
 
@@ -192,12 +306,14 @@ pub(crate) fn despawn_ball(
     mut commands: Commands,
     query_baseball: Query<Entity, With<BaseballMarker>>,
     active_batter_tracker: Res<ActiveBatterTracker>,
+    field: Res<FieldGeometry>,
     mut ev_redraw: EventWriter<RedrawStrikezone>,
 ) {
     for baseball in query_baseball.iter() {
         commands.entity(baseball).despawn_recursive();
         ev_redraw.send(RedrawStrikezone {
             batter_height: active_batter_tracker.height,
+            rule: field.strikezone,
         });
     }
 }
@@ -205,6 +321,8 @@ pub(crate) fn despawn_ball(
 pub(crate) fn spawn_ball(
     mut commands: Commands,
     selected_pitch_parameters: Res<SelectedPitchParameters>,
+    field: Res<FieldGeometry>,
+    aero_profile: Res<AeroProfile>,
     query_baseball: Query<Entity, With<BaseballMarker>>,
 ) {
     if let Ok(_) = query_baseball.get_single() {
@@ -213,21 +331,19 @@ pub(crate) fn spawn_ball(
         commands
             .spawn((
                 BaseballMarker,
+                GameSceneMarker(BullpenScene),
                 Name::new("ball"),
                 //
                 BaseballFlightBundle::default(),
                 //
                 ExternalForce::default(),
                 TransformBundle::from_transform(Transform::from_translation(
-                    match selected_pitch_parameters.0.pitching_arm {
-                        PitchingArm::Lefty => PITCH_DEFAULT_STARTING_POINT_LEFTY,
-                        PitchingArm::Righty => PITCH_DEFAULT_STARTING_POINT_RIGHTY,
-                    },
+                    selected_pitch_parameters.0.release_point(&field),
                 )),
                 Velocity::default(),
                 //
                 Restitution {
-                    coefficient: 0.546,
+                    coefficient: aero_profile.restitution,
                     combine_rule: CoefficientCombineRule::Min,
                 },
                 //
@@ -235,12 +351,13 @@ pub(crate) fn spawn_ball(
                 RenderLayers::from_layers(&[0]),
                 Ccd::enabled(),
             ))
+            // sized to the ball being thrown
+            .insert((
+                Collider::ball(aero_profile.radius()),
+                ColliderMassProperties::Mass(aero_profile.mass),
+            ))
             .with_children(|child| {
-                let seam_y_angle = selected_pitch_parameters.0.seam_y_angle;
-                let seam_z_angle = selected_pitch_parameters.0.seam_z_angle;
-
-                let rot = Quat::from_rotation_y(-seam_y_angle)
-                    .mul_quat(Quat::from_rotation_z(seam_z_angle));
+                let rot = selected_pitch_parameters.0.seam_rotation();
 
                 child.spawn((
                     BlueprintInfo::from_path("blueprints/Baseball.glb"),
@@ -248,66 +365,123 @@ pub(crate) fn spawn_ball(
                     HideUntilReady,
                     RenderLayers::from_layers(&[0]),
                     TransformBundle::from_transform(
-                        Transform::from_scale(0.037 * Vec3::new(1., 1.0, 1.0)).with_rotation(rot),
+                        Transform::from_scale(
+                            BASEBALL_BLUEPRINT_SCALE * aero_profile.radius() / BASEBALL_RADIUS
+                                * Vec3::ONE,
+                        )
+                        .with_rotation(rot),
                     ),
                 ));
             });
     }
 }
 
+/// Direction from the release point toward whatever the pitcher camera is aiming at.
+pub(crate) fn aim_direction(
+    rapier_context: &RapierContext,
+    camera_global_transform: &GlobalTransform,
+    start_pos: Vec3,
+) -> Vec3 {
+    let camera_transform = camera_global_transform.compute_transform();
+    let ray_origin = camera_transform.translation;
+    let ray_dir = camera_transform.rotation.mul_vec3(-Vec3::Z).normalize();
+    let max_toi = f32::INFINITY;
+    let query = QueryFilter::new();
+
+    match rapier_context.cast_ray(ray_origin, ray_dir, max_toi, true, query) {
+        Some((_entity, toi)) => {
+            let aim_point = ray_origin + ray_dir * toi;
+            (aim_point - start_pos).normalize()
+        }
+        None => ray_dir,
+    }
+}
+
+/// Draws where the pitch in hand will go if it is released now.
+pub(crate) fn draw_predicted_pitch(
+    mut gizmos: Gizmos,
+    selected_pitch_parameters: Res<SelectedPitchParameters>,
+    field: Res<FieldGeometry>,
+    aero_environment: Res<AeroEnvironment>,
+    aero_profile: Res<AeroProfile>,
+    aero_effects: Res<AeroEffects>,
+    rapier_context: Res<RapierContext>,
+    camera_query: Query<&GlobalTransform, With<PitcherCameraMarker>>,
+    query_baseball: Query<(), (With<BaseballMarker>, Without<AeroState>)>,
+) {
+    if query_baseball.is_empty() {
+        return;
+    }
+    let Ok(camera_global_transform) = camera_query.get_single() else {
+        return;
+    };
+    let pitch = selected_pitch_parameters.0;
+    let direction = aim_direction(
+        &rapier_context,
+        camera_global_transform,
+        pitch.release_point(&field),
+    );
+    let trajectory = simulate_pitch(
+        &pitch,
+        direction,
+        &field,
+        &aero_environment,
+        &aero_profile,
+        &aero_effects,
+    );
+
+    let color = Color::srgba(0.2, 0.9, 0.3, 0.8);
+    gizmos.linestrip(trajectory.positions(), color);
+    if let Some(plate_location) = trajectory.plate_location() {
+        gizmos.sphere(plate_location, Quat::IDENTITY, BASEBALL_RADIUS, color);
+        gizmos.rect(
+            plate_location,
+            Quat::IDENTITY,
+            Vec2::splat(BASEBALL_RADIUS * 4.),
+            color,
+        );
+    }
+}
+
 pub(crate) fn launch_ball(
     mut selected_pitch_parameters: ResMut<SelectedPitchParameters>,
+    mut thrown_pitch: ResMut<ThrownPitch>,
+    mut delivery: ResMut<Delivery>,
+    field: Res<FieldGeometry>,
     rapier_context: Res<RapierContext>,
     camera_query: Query<&GlobalTransform, With<PitcherCameraMarker>>,
     mut query_baseball: Query<(Entity, &mut Velocity), With<BaseballMarker>>,
     mut ev_activate_aerodynamics: EventWriter<ActivateAerodynamicsEvent>,
 ) {
+    // the wind-up ends with the release, ball or not
+    let outcome = delivery.release();
     if let Ok((entity, mut velocity)) = query_baseball.get_single_mut() {
         if let Ok(camera_global_transform) = camera_query.get_single() {
-            let camera_transform = camera_global_transform.compute_transform();
-            let ray_origin = camera_transform.translation;
-            let start_pos = match selected_pitch_parameters.0.pitching_arm {
-                PitchingArm::Lefty => PITCH_DEFAULT_STARTING_POINT_LEFTY,
-                PitchingArm::Righty => PITCH_DEFAULT_STARTING_POINT_RIGHTY,
-            };
-            let ray_dir = camera_transform.rotation.mul_vec3(-Vec3::Z).normalize();
-            let max_toi = f32::INFINITY;
-            let query = QueryFilter::new();
-
-            let direction = match rapier_context.cast_ray(ray_origin, ray_dir, max_toi, true, query)
-            {
-                Some((_entity, toi)) => {
-                    let aim_point = ray_origin + ray_dir * toi;
-                    (aim_point - start_pos).normalize()
-                }
-                None => ray_dir,
-            };
-
-            selected_pitch_parameters.0.direction = direction;
+            selected_pitch_parameters.0.direction = aim_direction(
+                &rapier_context,
+                camera_global_transform,
+                selected_pitch_parameters.0.release_point(&field),
+            );
         }
 
-        let PitchParams {
-            pitching_arm: _,
-            gyro_pole,
-            spin_efficiency,
-            speed,
-            spin_rate,
-            tilt,
-            direction,
-            seam_y_angle,
-            seam_z_angle,
-        } = selected_pitch_parameters.0;
-
-        let spin =
-            get_angular_velocity_from_parameters(tilt, spin_efficiency, spin_rate, gyro_pole);
+        let mut pitch = selected_pitch_parameters.0;
+        if let Some(outcome) = outcome {
+            pitch = outcome.apply(&pitch);
+        }
+        thrown_pitch.0 = pitch;
+        let BallState {
+            velocity: linvel,
+            spin: angvel,
+            ..
+        } = pitch.release_state(pitch.direction);
 
-        velocity.linvel = direction * speed * 0.44704; // 0.3048;
-        velocity.angvel = spin.from_baseball_coord_to_bevy();
+        velocity.linvel = linvel;
+        velocity.angvel = angvel;
 
         ev_activate_aerodynamics.send(ActivateAerodynamicsEvent {
             entity,
-            seam_y_angle,
-            seam_z_angle,
+            seam_y_angle: pitch.seam_y_angle,
+            seam_z_angle: pitch.seam_z_angle,
             //
             record_times: vec![],
             //
@@ -26,8 +26,33 @@ pub(crate) enum GameScenesSet<T: GameScene> {
     OnExitSet(T),
 }
 
-#[derive(Debug, States, Hash, Eq, PartialEq, Clone)]
+/// The scene picked in the scene select menu.
+/// Each [`GameScene`] is computed from this state, so switching it exits the
+/// current scene and enters the new one.
+#[derive(Debug, Default, States, Hash, Eq, PartialEq, Clone, Copy)]
+pub(crate) enum SelectedScene {
+    #[default]
+    Bullpen,
+    Test,
+}
+
+impl SelectedScene {
+    pub(crate) const ALL: [SelectedScene; 2] = [SelectedScene::Bullpen, SelectedScene::Test];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            SelectedScene::Bullpen => "Bullpen",
+            SelectedScene::Test => "Test",
+        }
+    }
+}
+
+/// Loading status of the current scene.
+/// Every scene starts in `Loading` and moves itself to `Loaded` once the
+/// blueprints and entities it needs are present.
+#[derive(Debug, Default, States, Hash, Eq, PartialEq, Clone, Copy)]
 pub(crate) enum SceneState {
+    #[default]
     Loading,
     Loaded,
 }
@@ -36,17 +61,87 @@ pub(crate) enum SceneState {
 #[reflect(Component)]
 pub(crate) struct GameSceneMarker<T: GameScene>(pub T);
 
-// app state(?) loading, etc...
-
 #[derive(Debug)]
 pub(crate) struct GameScenePlugin;
 
 impl Plugin for GameScenePlugin {
     fn build(&self, app: &mut App) {
         // default scene to start from
-        app.insert_state(BullpenScene);
-        // app.insert_state(TestScene);
+        app.init_state::<SelectedScene>();
+        app.init_state::<SceneState>();
 
         app.add_plugins(BullpenScene).add_plugins(TestScene);
+
+        app.add_systems(
+            Update,
+            (
+                loading_screen.run_if(in_state(SceneState::Loading)),
+                scene_select_menu
+                    .run_if(in_state(SceneState::Loaded))
+                    .run_if(cursor_unlocked),
+            ),
+        );
+    }
+}
+
+/// Despawns everything a scene or its plugins spawned, keyed on the scene's marker.
+pub(crate) fn despawn_scene_entities<T: GameScene>(
+    mut commands: Commands,
+    query_scene_entities: Query<Entity, With<GameSceneMarker<T>>>,
+) {
+    for entity in query_scene_entities.iter() {
+        commands.entity(entity).despawn_recursive();
     }
 }
+
+/// Puts a resource back to its default, so a scene starts fresh when re-entered.
+pub(crate) fn reset_resource<R: Resource + Default>(mut commands: Commands) {
+    commands.insert_resource(R::default());
+}
+
+pub(crate) fn cursor_unlocked(primary_window: Query<&Window, With<PrimaryWindow>>) -> bool {
+    primary_window
+        .get_single()
+        .map(|window| window.cursor.grab_mode == CursorGrabMode::None)
+        .unwrap_or(false)
+}
+
+pub(crate) fn loading_screen(
+    mut contexts: EguiContexts,
+    selected_scene: Res<State<SelectedScene>>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    egui::CentralPanel::default().show(ctx, |ui| {
+        ui.centered_and_justified(|ui| {
+            ui.vertical_centered(|ui| {
+                ui.heading(format!("Loading {}", selected_scene.get().label()));
+                ui.add_space(10.0);
+                ui.spinner();
+            });
+        });
+    });
+}
+
+pub(crate) fn scene_select_menu(
+    mut contexts: EguiContexts,
+    selected_scene: Res<State<SelectedScene>>,
+    mut next_selected_scene: ResMut<NextState<SelectedScene>>,
+    mut next_scene_state: ResMut<NextState<SceneState>>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    egui::Window::new("scenes")
+        .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-10.0, 10.0))
+        .resizable(false)
+        .show(ctx, |ui| {
+            for scene in SelectedScene::ALL {
+                let is_current = *selected_scene.get() == scene;
+                if ui.selectable_label(is_current, scene.label()).clicked() && !is_current {
+                    info!("switching scene to {:?}", scene);
+                    next_selected_scene.set(scene);
+                    next_scene_state.set(SceneState::Loading);
+                }
+            }
+        });
+}
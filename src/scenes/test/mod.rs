@@ -11,9 +11,20 @@ pub(crate) mod prelude {
 const PI: f32 = std::f32::consts::PI;
 
 // bullpen scene
-#[derive(Debug, Reflect, States, Hash, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Reflect, Hash, Eq, PartialEq, Clone, Copy)]
 pub(crate) struct TestScene;
 
+impl ComputedStates for TestScene {
+    type SourceStates = SelectedScene;
+
+    fn compute(sources: SelectedScene) -> Option<Self> {
+        match sources {
+            SelectedScene::Test => Some(TestScene),
+            _ => None,
+        }
+    }
+}
+
 impl GameScene for TestScene {
     fn configure_set(&self, app: &mut App) {
         app.configure_sets(
@@ -24,10 +35,8 @@ impl GameScene for TestScene {
             Update,
             GameScenesSet::UpdateSet(*self).run_if(in_state(*self)),
         )
-        .configure_sets(
-            OnExit(*self),
-            GameScenesSet::OnExitSet(*self).run_if(in_state(*self)),
-        );
+        // the scene state is already gone by the time OnExit runs
+        .configure_sets(OnExit(*self), GameScenesSet::OnExitSet(*self));
     }
 
     fn register_type(&self, app: &mut App) {
@@ -45,6 +54,8 @@ impl Plugin for TestScene {
         self.configure_set(app);
         self.add_events(app);
 
+        app.add_computed_state::<Self>();
+
         app.add_systems(
             OnEnter(Self),
             (
@@ -56,6 +67,10 @@ impl Plugin for TestScene {
                 .chain()
                 .in_set(GameScenesSet::OnEnterSet(*self)),
         )
+        .add_systems(
+            OnExit(Self),
+            despawn_scene_entities::<Self>.in_set(GameScenesSet::OnExitSet(*self)),
+        )
         .add_systems(
             Update,
             (
@@ -65,10 +80,27 @@ impl Plugin for TestScene {
             )
                 .chain()
                 .in_set(GameScenesSet::UpdateSet(*self)),
+        )
+        .add_systems(
+            Update,
+            check_scene_loaded
+                .run_if(in_state(SceneState::Loading))
+                .in_set(GameScenesSet::UpdateSet(*self)),
         );
     }
 }
 
+// nothing is spawned from blueprints here, so the camera is all we wait for
+fn check_scene_loaded(
+    query_camera: Query<(), With<FlyCam>>,
+    mut next_scene_state: ResMut<NextState<SceneState>>,
+) {
+    if !query_camera.is_empty() {
+        info!("test scene loaded");
+        next_scene_state.set(SceneState::Loaded);
+    }
+}
+
 fn spawn_camera(mut commands: Commands) {
     info!("spawn camera");
     commands.spawn((
@@ -84,33 +116,37 @@ fn spawn_camera(mut commands: Commands) {
                 .looking_at(Vec3::new(0., 1.6, 0.), Vec3::Y),
             ..default()
         },
+        GameSceneMarker(TestScene),
     ));
 }
 
 pub(crate) fn setup_sun(mut commands: Commands) {
     // directional 'sun' light
-    commands.spawn(DirectionalLightBundle {
-        directional_light: DirectionalLight {
-            illuminance: light_consts::lux::OVERCAST_DAY,
-            shadows_enabled: false,
-            ..default()
-        },
-        transform: Transform {
-            translation: Vec3::new(0.0, 2.0, 0.0),
-            rotation: Quat::from_rotation_x(PI / 4.),
+    commands.spawn((
+        DirectionalLightBundle {
+            directional_light: DirectionalLight {
+                illuminance: light_consts::lux::OVERCAST_DAY,
+                shadows_enabled: false,
+                ..default()
+            },
+            transform: Transform {
+                translation: Vec3::new(0.0, 2.0, 0.0),
+                rotation: Quat::from_rotation_x(PI / 4.),
+                ..default()
+            },
+            // The default cascade config is designed to handle large scenes.
+            // As this example has a much smaller world, we can tighten the shadow
+            // bounds for better visual quality.
+            cascade_shadow_config: CascadeShadowConfigBuilder {
+                first_cascade_far_bound: 4.0,
+                maximum_distance: 10.0,
+                ..default()
+            }
+            .into(),
             ..default()
         },
-        // The default cascade config is designed to handle large scenes.
-        // As this example has a much smaller world, we can tighten the shadow
-        // bounds for better visual quality.
-        cascade_shadow_config: CascadeShadowConfigBuilder {
-            first_cascade_far_bound: 4.0,
-            maximum_distance: 10.0,
-            ..default()
-        }
-        .into(),
-        ..default()
-    });
+        GameSceneMarker(TestScene),
+    ));
 }
 
 #[derive(Component)]
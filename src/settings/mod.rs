@@ -0,0 +1,137 @@
+use crate::prelude::*;
+use bevy::ecs::system::SystemParam;
+
+mod resources;
+pub(crate) mod storage;
+mod systems;
+
+use systems::*;
+
+pub(crate) mod prelude {
+    pub(crate) use super::*;
+    pub(crate) use resources::*;
+}
+
+/// Saved preferences, for the menu: the settings themselves and the control bindings.
+#[derive(SystemParam)]
+pub(crate) struct Preferences<'w> {
+    settings: ResMut<'w, UserSettings>,
+    status: Res<'w, SettingsStatus>,
+    pub units: ResMut<'w, Units>,
+    pub bindings: ControlBindings<'w>,
+}
+
+impl Preferences<'_> {
+    /// Grid rows for the units, camera, window, delivery and default preset settings.
+    pub(crate) fn settings(&mut self, ui: &mut egui::Ui) {
+        let mut units = *self.units;
+        units.settings(ui);
+        self.units.set_if_neq(units);
+
+        let mut settings = self.settings.clone();
+
+        ui.label("aim speed");
+        egui::Slider::new(&mut settings.aim_speed, 1.0_f32..=20.0_f32).ui(ui);
+        ui.end_row();
+
+        ui.label("field of view (°)");
+        egui::Slider::new(&mut settings.fov, 5.0_f32..=60.0_f32).ui(ui);
+        ui.end_row();
+
+        ui.label("zoom range (m)");
+        ui.horizontal(|ui| {
+            egui::Slider::new(&mut settings.zoom.0, 2.0_f32..=settings.zoom.1).ui(ui);
+            egui::Slider::new(&mut settings.zoom.1, settings.zoom.0..=30.0_f32).ui(ui);
+        });
+        ui.end_row();
+
+        ui.label("window");
+        egui::ComboBox::from_id_salt("display mode")
+            .selected_text(settings.display_mode.label())
+            .show_ui(ui, |ui| {
+                for mode in DisplayMode::ALL {
+                    ui.selectable_value(&mut settings.display_mode, mode, mode.label());
+                }
+            });
+        ui.end_row();
+
+        ui.label("delivery");
+        egui::ComboBox::from_id_salt("delivery mode")
+            .selected_text(settings.delivery.label())
+            .show_ui(ui, |ui| {
+                for mode in DeliveryMode::ALL {
+                    ui.selectable_value(&mut settings.delivery, mode, mode.label());
+                }
+            });
+        ui.end_row();
+
+        ui.label("starting environment");
+        egui::ComboBox::from_id_salt("default environment")
+            .selected_text(settings.environment.label())
+            .show_ui(ui, |ui| {
+                for preset in EnvironmentPreset::ALL {
+                    ui.selectable_value(&mut settings.environment, preset, preset.label());
+                }
+            });
+        ui.end_row();
+
+        ui.label("starting field");
+        egui::ComboBox::from_id_salt("default field")
+            .selected_text(settings.field.label())
+            .show_ui(ui, |ui| {
+                for preset in FieldPreset::ALL {
+                    ui.selectable_value(&mut settings.field, preset, preset.label());
+                }
+            });
+        ui.end_row();
+
+        ui.label("starting ball");
+        egui::ComboBox::from_id_salt("default ball")
+            .selected_text(settings.ball.label())
+            .show_ui(ui, |ui| {
+                for preset in BallPreset::ALL {
+                    ui.selectable_value(&mut settings.ball, preset, preset.label());
+                }
+            });
+        ui.end_row();
+
+        if ui.button("restore defaults").clicked() {
+            settings = UserSettings::default();
+            self.units.set_if_neq(settings.units);
+        }
+        ui.label(&self.status.0);
+        ui.end_row();
+
+        self.settings.set_if_neq(settings);
+    }
+}
+
+/// Starts from the [`UserSettings`] loaded at startup and saves them once they stop changing.
+/// Settings that belong to a scene are applied and recorded by the scene.
+#[derive(Debug)]
+pub(crate) struct SettingsPlugin {
+    pub settings: UserSettings,
+}
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.settings.units)
+            .insert_resource(self.settings.clone())
+            .init_resource::<SettingsStatus>();
+
+        app.add_systems(Startup, apply_default_presets);
+
+        app.add_systems(
+            Update,
+            (
+                apply_display_mode,
+                apply_camera_settings,
+                record_units,
+                record_effects,
+            )
+                .chain(),
+        )
+        // after everything that could change the settings or ask to exit this frame
+        .add_systems(Last, save_settings);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub(crate) const SETTINGS_FILE: &str = "settings.ron";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub(crate) enum DisplayMode {
+    #[default]
+    Windowed,
+    BorderlessFullscreen,
+    Fullscreen,
+}
+
+impl DisplayMode {
+    pub(crate) const ALL: [DisplayMode; 3] = [
+        DisplayMode::Windowed,
+        DisplayMode::BorderlessFullscreen,
+        DisplayMode::Fullscreen,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            DisplayMode::Windowed => "windowed",
+            DisplayMode::BorderlessFullscreen => "borderless",
+            DisplayMode::Fullscreen => "fullscreen",
+        }
+    }
+
+    pub(crate) fn window_mode(&self) -> WindowMode {
+        match self {
+            DisplayMode::Windowed => WindowMode::Windowed,
+            DisplayMode::BorderlessFullscreen => WindowMode::BorderlessFullscreen,
+            DisplayMode::Fullscreen => WindowMode::Fullscreen,
+        }
+    }
+}
+
+/// Preferences kept between launches. Saved once they stop changing.
+#[derive(Debug, Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct UserSettings {
+    pub units: Units,
+    /// handed to `BaseballFlightPlugin` at startup
+    pub effects: AeroEffects,
+    /// pitcher camera turn rate
+    pub aim_speed: f32,
+    /// pitcher camera vertical field of view in degrees
+    pub fov: f32,
+    /// closest and farthest the pitcher camera zooms, in meters
+    pub zoom: (f32, f32),
+    pub display_mode: DisplayMode,
+    pub delivery: DeliveryMode,
+    pub layout: ViewLayout,
+    pub ghost_trajectories: bool,
+    pub force_arrows: bool,
+    pub force_plot: bool,
+    /// applied at startup
+    pub environment: EnvironmentPreset,
+    /// applied at startup
+    pub field: FieldPreset,
+    /// applied at startup, after the field
+    pub ball: BallPreset,
+}
+
+impl Default for UserSettings {
+    fn default() -> Self {
+        Self {
+            units: Units::default(),
+            effects: AeroEffects::default(),
+            aim_speed: 5.,
+            fov: 10.,
+            zoom: (8., 15.),
+            display_mode: DisplayMode::default(),
+            delivery: DeliveryMode::default(),
+            layout: ViewLayout::default(),
+            ghost_trajectories: false,
+            force_arrows: false,
+            force_plot: false,
+            environment: EnvironmentPreset::SeaLevel,
+            field: FieldPreset::Mlb,
+            ball: BallPreset::Mlb,
+        }
+    }
+}
+
+impl UserSettings {
+    pub(crate) fn load() -> Self {
+        storage::load(SETTINGS_FILE).unwrap_or_default()
+    }
+}
+
+/// Result of the last save, shown in the menu.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct SettingsStatus(pub String);
@@ -0,0 +1,64 @@
+//! Small text files kept with the user's configuration: the platform config directory
+//! on desktop, local storage in the browser.
+
+use crate::errors::Error;
+
+#[cfg(not(target_arch = "wasm32"))]
+const APP_DIR: &str = "the-bullpen-game";
+
+#[cfg(not(target_arch = "wasm32"))]
+fn config_dir() -> Option<std::path::PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(APP_DIR))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn read(name: &str) -> Option<String> {
+    std::fs::read_to_string(config_dir()?.join(name)).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn write(name: &str, text: &str) -> Result<(), Error> {
+    let dir = config_dir()
+        .ok_or_else(|| Error::GenericError("no config directory on this platform".to_string()))?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| Error::GenericError(format!("couldn't create {}: {}", dir.display(), e)))?;
+    let path = dir.join(name);
+    std::fs::write(&path, text)
+        .map_err(|e| Error::GenericError(format!("couldn't write {}: {}", path.display(), e)))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn read(name: &str) -> Option<String> {
+    local_storage()?.get_item(name).ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn write(name: &str, text: &str) -> Result<(), Error> {
+    local_storage()
+        .ok_or_else(|| Error::GenericError("no local storage in this browser".to_string()))?
+        .set_item(name, text)
+        .map_err(|_| Error::GenericError(format!("couldn't store {}", name)))
+}
+
+/// Reads `name` as RON, or `None` if it's missing or can't be parsed.
+pub(crate) fn load<T: serde::de::DeserializeOwned>(name: &str) -> Option<T> {
+    let text = read(name)?;
+    match bevy::asset::ron::from_str(&text) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            bevy::log::warn!("couldn't read {}: {}", name, e);
+            None
+        }
+    }
+}
+
+pub(crate) fn save<T: serde::Serialize>(name: &str, value: &T) -> Result<(), Error> {
+    let text = bevy::asset::ron::ser::to_string_pretty(value, Default::default())
+        .map_err(|e| Error::GenericError(format!("couldn't write {}: {}", name, e)))?;
+    write(name, &text)
+}
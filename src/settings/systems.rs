@@ -0,0 +1,106 @@
+use crate::prelude::*;
+
+/// seconds the settings have to stay put before they're written,
+/// so dragging a slider doesn't rewrite the file every frame
+const SAVE_DELAY: f32 = 0.5;
+
+/// Saves the settings once they've stopped changing, or right away when the game exits.
+pub(crate) fn save_settings(
+    settings: Res<UserSettings>,
+    time: Res<Time<Real>>,
+    mut changed_at: Local<Option<f32>>,
+    mut status: ResMut<SettingsStatus>,
+    mut ev_exit: EventReader<AppExit>,
+) {
+    if settings.is_changed() && !settings.is_added() {
+        *changed_at = Some(time.elapsed_seconds());
+    }
+    let Some(at) = *changed_at else {
+        return;
+    };
+    let exiting = ev_exit.read().count() > 0;
+    if !exiting && time.elapsed_seconds() - at < SAVE_DELAY {
+        return;
+    }
+    *changed_at = None;
+    status.0 = match storage::save(SETTINGS_FILE, &*settings) {
+        Ok(()) => "saved".to_string(),
+        Err(e) => e.to_string(),
+    };
+}
+
+pub(crate) fn record_units(units: Res<Units>, mut settings: ResMut<UserSettings>) {
+    if units.is_changed() && settings.units != *units {
+        settings.units = *units;
+    }
+}
+
+pub(crate) fn record_effects(effects: Res<AeroEffects>, mut settings: ResMut<UserSettings>) {
+    if effects.is_changed() && settings.effects != *effects {
+        settings.effects = *effects;
+    }
+}
+
+/// Starts the session on the preferred environment, field and ball.
+pub(crate) fn apply_default_presets(
+    settings: Res<UserSettings>,
+    mut environment: ResMut<AeroEnvironment>,
+    mut profile: ResMut<AeroProfile>,
+    mut field: ResMut<FieldGeometry>,
+) {
+    *environment = settings.environment.environment();
+    *field = settings.field.geometry();
+    *profile = AeroProfile {
+        drag_model: profile.drag_model,
+        lift_model: profile.lift_model,
+        ..settings.ball.profile()
+    };
+}
+
+pub(crate) fn apply_display_mode(
+    settings: Res<UserSettings>,
+    mut primary_window: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let mode = settings.display_mode.window_mode();
+    for mut window in primary_window.iter_mut() {
+        if window.mode != mode {
+            window.mode = mode;
+        }
+    }
+}
+
+/// Keeps the pitcher camera on the preferred aim speed, zoom range and field of view.
+/// Zoom is only touched when its range changes, since setting it resets the zoom level.
+pub(crate) fn apply_camera_settings(
+    settings: Res<UserSettings>,
+    mut applied_zoom: Local<Option<(f32, f32)>>,
+    mut query_pitcher_camera: Query<
+        (&mut ThirdPersonCamera, &mut blenvy::Projection),
+        With<PitcherCameraMarker>,
+    >,
+) {
+    for (mut pitcher_aim, mut projection) in query_pitcher_camera.iter_mut() {
+        if !settings.is_changed() && !pitcher_aim.is_added() {
+            continue;
+        }
+        if pitcher_aim.is_added() {
+            *applied_zoom = None;
+        }
+        if pitcher_aim.aim_speed != settings.aim_speed {
+            pitcher_aim.aim_speed = settings.aim_speed;
+        }
+        if *applied_zoom != Some(settings.zoom) {
+            pitcher_aim.zoom = Zoom::new(settings.zoom.0, settings.zoom.1);
+            *applied_zoom = Some(settings.zoom);
+        }
+        if let blenvy::Projection::Perspective(perspective) = projection.as_mut() {
+            let fov = settings.fov.to_radians();
+            if perspective.fov != fov {
+                perspective.fov = fov;
+            }
+        }
+    }
+}
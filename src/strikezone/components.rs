@@ -9,6 +9,32 @@ pub(crate) struct StrikezoneHomeplateMarker;
 #[reflect(Component)]
 pub(crate) struct BallStrikezoneCollisionMarker;
 
+/// Heights of the strike zone as fractions of the batter's height.
+#[derive(Debug, Reflect, Clone, Copy, PartialEq)]
+pub(crate) struct StrikezoneRule {
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl Default for StrikezoneRule {
+    fn default() -> Self {
+        Self {
+            top: DEFAULT_HEIGHT_TOP_PERCENTAGE,
+            bottom: DEFAULT_HEIGHT_BOTTOM_PERCENTAGE,
+        }
+    }
+}
+
+impl StrikezoneRule {
+    /// half height and center height of the zone in meters
+    pub(crate) fn zone(&self, batter_height: f32) -> (f32, f32) {
+        (
+            (self.top - self.bottom) * 0.5 * batter_height,
+            (self.top + self.bottom) * 0.5 * batter_height,
+        )
+    }
+}
+
 #[derive(Debug, Component, Reflect)]
 #[reflect(Component)]
 pub(crate) enum StrikezonePanel {
@@ -3,11 +3,13 @@ use crate::prelude::*;
 #[derive(Debug, Event)]
 pub(crate) struct SpawnStrikezone {
     pub batter_height: f32,
+    pub rule: StrikezoneRule,
 }
 
 #[derive(Debug, Event)]
 pub(crate) struct RedrawStrikezone {
     pub batter_height: f32,
+    pub rule: StrikezoneRule,
 }
 
 #[derive(Debug, Event)]
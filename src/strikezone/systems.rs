@@ -6,18 +6,11 @@ pub(crate) fn spawn_strikezone_system(
     query: Query<(Entity, &Transform), With<StrikezoneHomeplateMarker>>,
 ) {
     for ev in ev_spawn.read() {
-        let batter_height = ev.batter_height;
         for (home_plate_entity, _) in query.iter() {
             commands
                 .entity(home_plate_entity)
                 .with_children(|children| {
-                    let half_height = (DEFAULT_HEIGHT_TOP_PERCENTAGE
-                        - DEFAULT_HEIGHT_BOTTOM_PERCENTAGE)
-                        * 0.5
-                        * batter_height;
-                    let pos_y = (DEFAULT_HEIGHT_TOP_PERCENTAGE + DEFAULT_HEIGHT_BOTTOM_PERCENTAGE)
-                        * 0.5
-                        * batter_height;
+                    let (half_height, pos_y) = ev.rule.zone(ev.batter_height);
 
                     children.spawn(StrikezonePanelBundle::new_front(half_height, pos_y));
                     children.spawn(StrikezonePanelBundle::new_back(half_height, pos_y));
@@ -34,15 +27,8 @@ pub(crate) fn update_strikezone_panel_system(
     mut ev_redraw: EventReader<RedrawStrikezone>,
 ) {
     for ev in ev_redraw.read() {
-        let batter_height = ev.batter_height;
         for (mut transform, mut collider, mut panel) in query_strikezone.iter_mut() {
-            //
-            let half_height = (DEFAULT_HEIGHT_TOP_PERCENTAGE - DEFAULT_HEIGHT_BOTTOM_PERCENTAGE)
-                * 0.5
-                * batter_height;
-            let pos_y = (DEFAULT_HEIGHT_TOP_PERCENTAGE + DEFAULT_HEIGHT_BOTTOM_PERCENTAGE)
-                * 0.5
-                * batter_height;
+            let (half_height, pos_y) = ev.rule.zone(ev.batter_height);
             panel.clear(Vec2::new(DEFAULT_LENGTH_HALF, half_height));
             // clear collision records
             for record_entity in query_collision_record.iter() {
@@ -62,8 +48,9 @@ pub(crate) fn update_strikezone_panel_system(
     }
 }
 
-pub(crate) fn record_strikezone_collision_system(
+pub(crate) fn record_strikezone_collision_system<T: GameScene>(
     mut commands: Commands,
+    scene: Res<State<T>>,
     mut ev_record: EventReader<RecordStrikezoneCollision>,
     mut query_strikezone: Query<(&mut StrikezonePanel, &GlobalTransform)>,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -78,6 +65,7 @@ pub(crate) fn record_strikezone_collision_system(
                 commands
                     .spawn((
                         BallStrikezoneCollisionMarker,
+                        GameSceneMarker(scene.get().clone()),
                         InheritedVisibility::VISIBLE,
                         TransformBundle::from(Transform::from_translation(ev.collision_point)),
                     ))
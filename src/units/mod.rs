@@ -0,0 +1,419 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+pub(crate) mod prelude {
+    pub(crate) use super::*;
+}
+
+pub(crate) const MPH_TO_MPS: f32 = 0.44704;
+pub(crate) const M_TO_INCHES: f32 = M_TO_FEET * 12.;
+pub(crate) const RADS_TO_RPM: f32 = 60. / std::f32::consts::TAU;
+const HPA_PER_INHG: f32 = 33.8639;
+const KG_PER_OZ: f32 = 0.0283495;
+
+/// A unit a quantity can be shown in. Values are stored in the quantity's base unit:
+/// m/s, meters, rad/s, °C, hPa and kg.
+pub(crate) trait Unit: Copy + PartialEq {
+    fn symbol(&self) -> &'static str;
+
+    /// `shown = base * scale + offset`
+    fn scale_offset(&self) -> (f32, f32);
+
+    fn from_base(&self, value: f32) -> f32 {
+        let (scale, offset) = self.scale_offset();
+        value * scale + offset
+    }
+
+    fn to_base(&self, value: f32) -> f32 {
+        let (scale, offset) = self.scale_offset();
+        (value - offset) / scale
+    }
+
+    /// `value`, in the base unit, shown with its symbol.
+    fn format(&self, value: f32, decimals: usize) -> String {
+        format!("{:.*} {}", decimals, self.from_base(value), self.symbol())
+    }
+}
+
+/// pitch and wind speeds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum SpeedUnit {
+    Mph,
+    Kmh,
+    Mps,
+}
+
+impl SpeedUnit {
+    pub(crate) const ALL: [SpeedUnit; 3] = [SpeedUnit::Mph, SpeedUnit::Kmh, SpeedUnit::Mps];
+}
+
+impl Unit for SpeedUnit {
+    fn symbol(&self) -> &'static str {
+        match self {
+            SpeedUnit::Mph => "mph",
+            SpeedUnit::Kmh => "km/h",
+            SpeedUnit::Mps => "m/s",
+        }
+    }
+
+    fn scale_offset(&self) -> (f32, f32) {
+        match self {
+            SpeedUnit::Mph => (1. / MPH_TO_MPS, 0.),
+            SpeedUnit::Kmh => (3.6, 0.),
+            SpeedUnit::Mps => (1., 0.),
+        }
+    }
+}
+
+/// field dimensions, heights and altitude
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum DistanceUnit {
+    Feet,
+    Meters,
+}
+
+impl DistanceUnit {
+    pub(crate) const ALL: [DistanceUnit; 2] = [DistanceUnit::Feet, DistanceUnit::Meters];
+}
+
+impl Unit for DistanceUnit {
+    fn symbol(&self) -> &'static str {
+        match self {
+            DistanceUnit::Feet => "ft",
+            DistanceUnit::Meters => "m",
+        }
+    }
+
+    fn scale_offset(&self) -> (f32, f32) {
+        match self {
+            DistanceUnit::Feet => (M_TO_FEET, 0.),
+            DistanceUnit::Meters => (1., 0.),
+        }
+    }
+}
+
+/// break, plate location and ball dimensions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum LengthUnit {
+    Inches,
+    Centimeters,
+}
+
+impl LengthUnit {
+    pub(crate) const ALL: [LengthUnit; 2] = [LengthUnit::Inches, LengthUnit::Centimeters];
+}
+
+impl Unit for LengthUnit {
+    fn symbol(&self) -> &'static str {
+        match self {
+            LengthUnit::Inches => "in",
+            LengthUnit::Centimeters => "cm",
+        }
+    }
+
+    fn scale_offset(&self) -> (f32, f32) {
+        match self {
+            LengthUnit::Inches => (M_TO_INCHES, 0.),
+            LengthUnit::Centimeters => (100., 0.),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum SpinUnit {
+    Rpm,
+    RadiansPerSecond,
+}
+
+impl SpinUnit {
+    pub(crate) const ALL: [SpinUnit; 2] = [SpinUnit::Rpm, SpinUnit::RadiansPerSecond];
+}
+
+impl Unit for SpinUnit {
+    fn symbol(&self) -> &'static str {
+        match self {
+            SpinUnit::Rpm => "rpm",
+            SpinUnit::RadiansPerSecond => "rad/s",
+        }
+    }
+
+    fn scale_offset(&self) -> (f32, f32) {
+        match self {
+            SpinUnit::Rpm => (RADS_TO_RPM, 0.),
+            SpinUnit::RadiansPerSecond => (1., 0.),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum TemperatureUnit {
+    Fahrenheit,
+    Celsius,
+}
+
+impl TemperatureUnit {
+    pub(crate) const ALL: [TemperatureUnit; 2] =
+        [TemperatureUnit::Fahrenheit, TemperatureUnit::Celsius];
+}
+
+impl Unit for TemperatureUnit {
+    fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Fahrenheit => "°F",
+            TemperatureUnit::Celsius => "°C",
+        }
+    }
+
+    fn scale_offset(&self) -> (f32, f32) {
+        match self {
+            TemperatureUnit::Fahrenheit => (1.8, 32.),
+            TemperatureUnit::Celsius => (1., 0.),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum PressureUnit {
+    InHg,
+    Hpa,
+}
+
+impl PressureUnit {
+    pub(crate) const ALL: [PressureUnit; 2] = [PressureUnit::InHg, PressureUnit::Hpa];
+}
+
+impl Unit for PressureUnit {
+    fn symbol(&self) -> &'static str {
+        match self {
+            PressureUnit::InHg => "inHg",
+            PressureUnit::Hpa => "hPa",
+        }
+    }
+
+    fn scale_offset(&self) -> (f32, f32) {
+        match self {
+            PressureUnit::InHg => (1. / HPA_PER_INHG, 0.),
+            PressureUnit::Hpa => (1., 0.),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum MassUnit {
+    Ounces,
+    Grams,
+}
+
+impl MassUnit {
+    pub(crate) const ALL: [MassUnit; 2] = [MassUnit::Ounces, MassUnit::Grams];
+}
+
+impl Unit for MassUnit {
+    fn symbol(&self) -> &'static str {
+        match self {
+            MassUnit::Ounces => "oz",
+            MassUnit::Grams => "g",
+        }
+    }
+
+    fn scale_offset(&self) -> (f32, f32) {
+        match self {
+            MassUnit::Ounces => (1. / KG_PER_OZ, 0.),
+            MassUnit::Grams => (1000., 0.),
+        }
+    }
+}
+
+/// The unit every displayed quantity is shown in, chosen per quantity.
+#[derive(Debug, Resource, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Units {
+    pub speed: SpeedUnit,
+    pub distance: DistanceUnit,
+    pub length: LengthUnit,
+    pub spin: SpinUnit,
+    pub temperature: TemperatureUnit,
+    pub pressure: PressureUnit,
+    pub mass: MassUnit,
+}
+
+impl Default for Units {
+    fn default() -> Self {
+        Self::IMPERIAL
+    }
+}
+
+impl Units {
+    pub(crate) const IMPERIAL: Units = Units {
+        speed: SpeedUnit::Mph,
+        distance: DistanceUnit::Feet,
+        length: LengthUnit::Inches,
+        spin: SpinUnit::Rpm,
+        temperature: TemperatureUnit::Fahrenheit,
+        pressure: PressureUnit::InHg,
+        mass: MassUnit::Ounces,
+    };
+
+    pub(crate) const METRIC: Units = Units {
+        speed: SpeedUnit::Kmh,
+        distance: DistanceUnit::Meters,
+        length: LengthUnit::Centimeters,
+        spin: SpinUnit::Rpm,
+        temperature: TemperatureUnit::Celsius,
+        pressure: PressureUnit::Hpa,
+        mass: MassUnit::Grams,
+    };
+
+    /// Grid rows picking the unit of each quantity.
+    pub(crate) fn settings(&mut self, ui: &mut egui::Ui) {
+        ui.label("units");
+        ui.horizontal(|ui| {
+            if ui.button("imperial").clicked() {
+                *self = Units::IMPERIAL;
+            }
+            if ui.button("metric").clicked() {
+                *self = Units::METRIC;
+            }
+        });
+        ui.end_row();
+
+        unit_combo(ui, "speed", &mut self.speed, &SpeedUnit::ALL);
+        unit_combo(ui, "distance", &mut self.distance, &DistanceUnit::ALL);
+        unit_combo(ui, "break and location", &mut self.length, &LengthUnit::ALL);
+        unit_combo(ui, "spin", &mut self.spin, &SpinUnit::ALL);
+        unit_combo(
+            ui,
+            "temperature",
+            &mut self.temperature,
+            &TemperatureUnit::ALL,
+        );
+        unit_combo(ui, "pressure", &mut self.pressure, &PressureUnit::ALL);
+        unit_combo(ui, "mass", &mut self.mass, &MassUnit::ALL);
+    }
+}
+
+fn unit_combo<U: Unit>(ui: &mut egui::Ui, label: &str, unit: &mut U, options: &[U]) {
+    ui.label(label);
+    egui::ComboBox::from_id_salt(label)
+        .selected_text(unit.symbol())
+        .show_ui(ui, |ui| {
+            for option in options {
+                ui.selectable_value(unit, *option, option.symbol());
+            }
+        });
+    ui.end_row();
+}
+
+/// `name (symbol)`, for labels and column headers.
+pub(crate) fn unit_label(name: &str, unit: impl Unit) -> String {
+    format!("{} ({})", name, unit.symbol())
+}
+
+/// A slider over `value`, kept in the base unit, shown in `unit`. `range` is in the base unit
+/// too. Only writes back when dragged, so values don't drift through the conversion.
+pub(crate) fn unit_slider(
+    ui: &mut egui::Ui,
+    value: &mut f32,
+    range: RangeInclusive<f32>,
+    unit: impl Unit,
+) -> egui::Response {
+    let mut shown = unit.from_base(*value);
+    let response = egui::Slider::new(
+        &mut shown,
+        unit.from_base(*range.start())..=unit.from_base(*range.end()),
+    )
+    .ui(ui);
+    if response.changed() {
+        *value = unit.to_base(shown);
+    }
+    response
+}
+
+/// `value` in `from`, converted to `to`.
+pub(crate) fn convert<U: Unit>(value: f32, from: U, to: U) -> f32 {
+    to.from_base(from.to_base(value))
+}
+
+/// A drag value over `value`, kept in `stored`, shown in `shown`. `speed` is per point in
+/// the stored unit.
+pub(crate) fn unit_drag<U: Unit>(
+    ui: &mut egui::Ui,
+    value: &mut f32,
+    stored: U,
+    shown: U,
+    speed: f32,
+) -> egui::Response {
+    let mut converted = convert(*value, stored, shown);
+    let response = ui.add(
+        egui::DragValue::new(&mut converted)
+            .speed(convert(speed, stored, shown) - convert(0., stored, shown)),
+    );
+    if response.changed() {
+        *value = convert(converted, shown, stored);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32, tolerance: f32) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    fn assert_round_trip<U: Unit>(units: &[U], values: &[f32]) {
+        for unit in units {
+            for &value in values {
+                assert_close(unit.to_base(unit.from_base(value)), value, 1e-3);
+                assert_close(unit.from_base(unit.to_base(value)), value, 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn round_trips_through_the_base_unit() {
+        let values = [-40., 0., 1., 18.44, 44.7, 1013.25];
+        assert_round_trip(&SpeedUnit::ALL, &values);
+        assert_round_trip(&DistanceUnit::ALL, &values);
+        assert_round_trip(&LengthUnit::ALL, &values);
+        assert_round_trip(&SpinUnit::ALL, &values);
+        assert_round_trip(&TemperatureUnit::ALL, &values);
+        assert_round_trip(&PressureUnit::ALL, &values);
+        assert_round_trip(&MassUnit::ALL, &values);
+    }
+
+    #[test]
+    fn converts_known_values() {
+        assert_close(SpeedUnit::Mph.to_base(100.), 44.704, 1e-3);
+        assert_close(SpeedUnit::Kmh.from_base(10.), 36., 1e-3);
+        assert_close(DistanceUnit::Feet.to_base(60.5), 18.44, 1e-2);
+        assert_close(LengthUnit::Inches.from_base(1.), 39.37, 1e-2);
+        assert_close(LengthUnit::Centimeters.from_base(0.0254), 2.54, 1e-4);
+        assert_close(SpinUnit::Rpm.to_base(2400.), 251.33, 1e-2);
+        assert_close(TemperatureUnit::Fahrenheit.from_base(0.), 32., 1e-4);
+        assert_close(TemperatureUnit::Fahrenheit.to_base(212.), 100., 1e-3);
+        assert_close(TemperatureUnit::Fahrenheit.from_base(-40.), -40., 1e-4);
+        assert_close(PressureUnit::InHg.to_base(29.92), 1013.2, 0.1);
+        assert_close(MassUnit::Ounces.to_base(5.125), 0.1453, 1e-4);
+        assert_close(MassUnit::Grams.from_base(0.145), 145., 1e-3);
+    }
+
+    #[test]
+    fn converts_between_units() {
+        assert_close(
+            convert(212., TemperatureUnit::Fahrenheit, TemperatureUnit::Celsius),
+            100.,
+            1e-3,
+        );
+        assert_close(convert(90., SpeedUnit::Mph, SpeedUnit::Kmh), 144.84, 1e-2);
+        assert_close(
+            convert(12., LengthUnit::Inches, LengthUnit::Centimeters),
+            30.48,
+            1e-3,
+        );
+    }
+}